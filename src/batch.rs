@@ -0,0 +1,118 @@
+use std::{num::NonZeroUsize, thread};
+
+use serde_json::Value;
+
+use crate::{
+    decode_input, dish_params,
+    policy::Execution,
+    utils::{DataRepresentation, SupportedFormats},
+    OperationError, Operations, ParsedParams,
+};
+
+/// One operation with one params object run over many inputs.
+/// Params are deserialized and checked once when the batch is created, so a bad params object
+/// is a single error instead of one per input. Every input gets its own result, in the order of the inputs.
+/// ``` rust
+/// let batch = Batch::new(Operations::SHA2, &json!({ "size": "sha256" }))?.threads(4);
+/// let digests = batch.run(lines.into_iter().map(DataRepresentation::String).collect());
+/// ```
+pub struct Batch {
+    operation: Operations,
+    params: ParsedParams,
+    input_format: Option<SupportedFormats>,
+    threads: usize,
+}
+
+impl Batch {
+    pub fn new(operation: Operations, params: &Value) -> Result<Self, OperationError> {
+        Ok(Self {
+            operation,
+            params: operation.parse_params(dish_params(params).as_deref())?,
+            input_format: None,
+            threads: 1,
+        })
+    }
+
+    /// Decodes every input from `format` first, like the `input_format` of a request.
+    pub fn input_format(mut self, format: SupportedFormats) -> Self {
+        self.input_format = Some(format);
+        self
+    }
+
+    /// Splits the inputs between `threads` threads, 0 uses every available core.
+    /// The threads run under the [`ExecutionPolicy`](crate::ExecutionPolicy) of the caller.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    pub fn run(
+        &self,
+        inputs: Vec<DataRepresentation>,
+    ) -> Vec<Result<DataRepresentation, OperationError>> {
+        let threads = match self.threads {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            threads => threads,
+        }
+        .clamp(1, inputs.len().max(1));
+
+        if threads == 1 {
+            return self.run_chunk(inputs);
+        }
+
+        let chunk_size = inputs.len().div_ceil(threads);
+        let mut chunks = Vec::with_capacity(threads);
+        let mut rest = inputs;
+        while !rest.is_empty() {
+            let tail = rest.split_off(chunk_size.min(rest.len()));
+            chunks.push(rest);
+            rest = tail;
+        }
+
+        let sizes: Vec<_> = chunks.iter().map(Vec::len).collect();
+        let execution = Execution::current();
+        thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .into_iter()
+                .map(|chunk| {
+                    let execution = execution.clone();
+                    scope.spawn(move || match execution {
+                        Some(execution) => execution.enter(|| self.run_chunk(chunk)),
+                        None => self.run_chunk(chunk),
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .zip(sizes)
+                .flat_map(|(handle, size)| match handle.join() {
+                    Ok(results) => results,
+                    Err(_) => (0..size)
+                        .map(|_| {
+                            Err(OperationError::Internal {
+                                details: "batch thread panicked".to_string(),
+                            })
+                        })
+                        .collect(),
+                })
+                .collect()
+        })
+    }
+
+    fn run_chunk(
+        &self,
+        inputs: Vec<DataRepresentation>,
+    ) -> Vec<Result<DataRepresentation, OperationError>> {
+        inputs
+            .into_iter()
+            .map(|input| {
+                let input = match &self.input_format {
+                    Some(format) => decode_input(input, format)?,
+                    None => input,
+                };
+                self.operation.run_with_params(input, &self.params)
+            })
+            .collect()
+    }
+}
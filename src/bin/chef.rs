@@ -0,0 +1,235 @@
+//! Runs operations and recipes from the command line.
+//! ``` sh
+//! echo -n hello | chef run SHA2 --param size=sha256
+//! chef run HMAC --params-file hmac.json --input key.bin --output base64
+//! chef recipe recipe.json --input data.txt
+//! echo -n 68656c6c6f | chef run MD5 --input-format hex
+//! chef list
+//! chef info Bcrypt
+//! ```
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use operations::{
+    catalogue, decode_input, DataRepresentation, OperationError, Operations, Recipe,
+    SupportedFormats,
+};
+use serde_json::{Map, Value};
+
+#[derive(Parser)]
+#[command(name = "chef", about = "Run operations and recipes on raw bytes")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one operation.
+    Run {
+        /// Name of the operation, see `chef list`.
+        operation: String,
+        /// Parameter as `key=value`, values are parsed as json when possible.
+        #[arg(short, long = "param", value_name = "KEY=VALUE")]
+        params: Vec<String>,
+        /// Json file with the `params` object.
+        #[arg(long, value_name = "FILE")]
+        params_file: Option<PathBuf>,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Bake a recipe, a json list of `{ "op": ..., "params": ... }` steps.
+    Recipe {
+        recipe: PathBuf,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// List every operation by category.
+    List,
+    /// Print the info and parameter schema of an operation.
+    Info { operation: String },
+}
+
+#[derive(clap::Args)]
+struct IoArgs {
+    /// File to read the input from, stdin when omitted.
+    #[arg(short, long, value_name = "FILE")]
+    input: Option<PathBuf>,
+    /// Encoding of a text input to decode first: utf8, hex, base64, binary, latin1 or decimal.
+    #[arg(long, value_name = "FORMAT", value_parser = parse_input_format)]
+    input_format: Option<SupportedFormats>,
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Raw)]
+    output: OutputFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Raw,
+    Hex,
+    Base64,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error[{}]: {}", err.code(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), OperationError> {
+    match command {
+        Command::Run {
+            operation,
+            params,
+            params_file,
+            io,
+        } => {
+            let operation = operation.parse::<Operations>()?;
+            let params = collect_params(operation, params, params_file)?;
+            let output = operation.run(read_input(&io)?, &params)?;
+            write_output(output, io.output)
+        }
+        Command::Recipe { recipe, io } => {
+            let recipe: Recipe = serde_json::from_slice(&read_file(&recipe)?).map_err(|err| {
+                OperationError::InvalidJson {
+                    details: err.to_string(),
+                }
+            })?;
+            let output = recipe.bake(read_input(&io)?)?;
+            write_output(output.output, io.output)
+        }
+        Command::List => {
+            for category in catalogue().categories {
+                println!("{}:", category.name_en);
+                for operation in category.operations {
+                    println!("    {}", operation.name());
+                }
+            }
+            Ok(())
+        }
+        Command::Info { operation } => {
+            let info = operation.parse::<Operations>()?.info_value();
+            println!("{}", serde_json::to_string_pretty(&info).unwrap());
+            Ok(())
+        }
+    }
+}
+
+fn collect_params(
+    operation: Operations,
+    params: Vec<String>,
+    params_file: Option<PathBuf>,
+) -> Result<Value, OperationError> {
+    let mut collected = match params_file {
+        Some(path) => match serde_json::from_slice(&read_file(&path)?) {
+            Ok(Value::Object(map)) => map,
+            Ok(_) => {
+                return Err(OperationError::invalid_parameter(
+                    "params",
+                    "Params file must contain a json object.",
+                    "Файл параметров должен содержать json объект.",
+                ))
+            }
+            Err(err) => {
+                return Err(OperationError::InvalidJson {
+                    details: err.to_string(),
+                })
+            }
+        },
+        None => Map::new(),
+    };
+
+    let schema = operation.info_value()["schema"].clone();
+    for param in params {
+        let Some((key, value)) = param.split_once('=') else {
+            return Err(OperationError::invalid_parameter(
+                format!("params.{param}"),
+                format!("Parameter `{param}` must look like `key=value`."),
+                format!("Параметр `{param}` должен иметь вид `ключ=значение`."),
+            ));
+        };
+        collected.insert(key.to_string(), param_value(&schema, key, value));
+    }
+
+    Ok(Value::Object(collected))
+}
+
+/// Values are json unless the schema says the parameter is a string,
+/// so `--param rounds=10` is a number while `--param key=10` stays a string key.
+fn param_value(schema: &Value, key: &str, value: &str) -> Value {
+    let accepts = |kind: &str| match &schema["$defs"]["Params"]["properties"][key]["type"] {
+        Value::String(type_name) => type_name == kind,
+        Value::Array(types) => types.iter().any(|type_name| type_name == kind),
+        _ => false,
+    };
+
+    match accepts("string") {
+        true => Value::String(value.to_string()),
+        false => serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string())),
+    }
+}
+
+fn read_file(path: &PathBuf) -> Result<Vec<u8>, OperationError> {
+    fs::read(path).map_err(|err| {
+        OperationError::invalid_input(
+            format!("Can't read `{}`: {err}.", path.display()),
+            format!("Не удалось прочитать `{}`: {err}.", path.display()),
+        )
+    })
+}
+
+fn read_input(io: &IoArgs) -> Result<DataRepresentation, OperationError> {
+    let bytes = match &io.input {
+        Some(path) => read_file(path)?,
+        None => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes).map_err(|err| {
+                OperationError::invalid_input(
+                    format!("Can't read stdin: {err}."),
+                    format!("Не удалось прочитать stdin: {err}."),
+                )
+            })?;
+            bytes
+        }
+    };
+    let input = DataRepresentation::from_bytes(bytes);
+
+    match &io.input_format {
+        Some(format) => decode_input(input, format),
+        None => Ok(input),
+    }
+}
+
+fn parse_input_format(format: &str) -> Result<SupportedFormats, String> {
+    serde_json::from_value(Value::from(format)).map_err(|err| err.to_string())
+}
+
+fn write_output(output: DataRepresentation, format: OutputFormat) -> Result<(), OperationError> {
+    let bytes = match format {
+        OutputFormat::Raw => output.into_bytes(),
+        OutputFormat::Hex => output
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>()
+            .into_bytes(),
+        OutputFormat::Base64 => Operations::ToBase64
+            .run(output, &Value::Object(Map::new()))?
+            .into_bytes(),
+    };
+
+    io::stdout()
+        .write_all(&bytes)
+        .map_err(|err| OperationError::Internal {
+            details: err.to_string(),
+        })
+}
@@ -0,0 +1,50 @@
+//! Serves every operation over http.
+//! Listens on `CHEF_API_ADDRESS` (default `127.0.0.1:8080`),
+//! bodies are limited by `CHEF_API_MAX_REQUEST_SIZE` bytes.
+//!
+//! Every request runs under an execution policy, `0` turns a limit off
+//! - `CHEF_API_MAX_MEMORY_COST` Argon2 memory in KiB, 65536 by default
+//! - `CHEF_API_MAX_TIME_COST` Argon2 iterations, 16 by default
+//! - `CHEF_API_MAX_PARALLELISM` Argon2 lanes, 4 by default
+//! - `CHEF_API_MAX_BCRYPT_COST` bcrypt rounds, 14 by default
+//! - `CHEF_API_MAX_INPUT_SIZE` bytes of the input of every operation, the request size by default
+//! - `CHEF_API_TIMEOUT` milliseconds a request may run, 30000 by default
+
+use std::time::Duration;
+
+use operations::{router, ExecutionPolicy, DEFAULT_MAX_REQUEST_SIZE};
+
+#[tokio::main]
+async fn main() {
+    let address =
+        std::env::var("CHEF_API_ADDRESS").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
+    let max_request_size = env_number("CHEF_API_MAX_REQUEST_SIZE", DEFAULT_MAX_REQUEST_SIZE);
+
+    let policy = ExecutionPolicy {
+        max_memory_cost: env_limit("CHEF_API_MAX_MEMORY_COST", 64 * 1024),
+        max_time_cost: env_limit("CHEF_API_MAX_TIME_COST", 16),
+        max_parallelism: env_limit("CHEF_API_MAX_PARALLELISM", 4),
+        max_bcrypt_cost: env_limit("CHEF_API_MAX_BCRYPT_COST", 14),
+        max_input_size: env_limit("CHEF_API_MAX_INPUT_SIZE", max_request_size),
+        timeout: env_limit("CHEF_API_TIMEOUT", 30_000).map(Duration::from_millis),
+    };
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .unwrap_or_else(|err| panic!("Can't listen on {address}: {err}"));
+
+    axum::serve(listener, router(max_request_size, policy))
+        .await
+        .expect("server error");
+}
+
+fn env_number<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn env_limit<T: std::str::FromStr + Default + PartialEq>(name: &str, default: T) -> Option<T> {
+    Some(env_number(name, default)).filter(|limit| *limit != T::default())
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Operations;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Hashing,
+    #[serde(rename = "Encryption / Encoding")]
+    EncryptionEncoding,
+    #[serde(rename = "Data format")]
+    DataFormat,
+    #[serde(rename = "Arithmetic / Logic")]
+    ArithmeticLogic,
+    #[serde(rename = "Flow control")]
+    FlowControl,
+    Utils,
+}
+
+impl Category {
+    pub const ALL: &'static [Category] = &[
+        Category::Hashing,
+        Category::EncryptionEncoding,
+        Category::DataFormat,
+        Category::ArithmeticLogic,
+        Category::FlowControl,
+        Category::Utils,
+    ];
+
+    pub fn name_en(&self) -> &'static str {
+        match self {
+            Category::Hashing => "Hashing",
+            Category::EncryptionEncoding => "Encryption / Encoding",
+            Category::DataFormat => "Data format",
+            Category::ArithmeticLogic => "Arithmetic / Logic",
+            Category::FlowControl => "Flow control",
+            Category::Utils => "Utils",
+        }
+    }
+
+    pub fn name_ru(&self) -> &'static str {
+        match self {
+            Category::Hashing => "Хеширование",
+            Category::EncryptionEncoding => "Шифрование / Кодирование",
+            Category::DataFormat => "Формат данных",
+            Category::ArithmeticLogic => "Арифметика / Логика",
+            Category::FlowControl => "Управление потоком",
+            Category::Utils => "Утилиты",
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct CategoryEntry {
+    pub category: Category,
+    pub name_en: &'static str,
+    pub name_ru: &'static str,
+    pub operations: Vec<Operations>,
+}
+
+/// Info of one operation: everything `/api/info/{name}` returns plus its category.
+#[derive(Serialize, Debug)]
+pub struct OperationEntry {
+    pub category: Category,
+    #[serde(flatten)]
+    pub info: Value,
+}
+
+/// Everything the crate can do, built from the operations registry.
+/// ``` json
+/// {
+///     "categories": [
+///         { "category": "Hashing", "name_en": "Hashing", "name_ru": "Хеширование", "operations": ["MD5", ...] }
+///     ],
+///     "operations": [
+///         { "category": "Hashing", "name": "MD5", "description_en": ..., "description_ru": ..., "schema": ... }
+///     ]
+/// }
+/// ```
+#[derive(Serialize, Debug)]
+pub struct Catalogue {
+    pub categories: Vec<CategoryEntry>,
+    pub operations: Vec<OperationEntry>,
+}
+
+pub fn catalogue() -> Catalogue {
+    let categories = Category::ALL
+        .iter()
+        .map(|&category| CategoryEntry {
+            category,
+            name_en: category.name_en(),
+            name_ru: category.name_ru(),
+            operations: Operations::ALL
+                .iter()
+                .copied()
+                .filter(|operation| operation.category() == category)
+                .collect(),
+        })
+        .filter(|entry| !entry.operations.is_empty())
+        .collect();
+
+    let operations = Operations::ALL
+        .iter()
+        .map(|operation| OperationEntry {
+            category: operation.category(),
+            info: operation.info_value(),
+        })
+        .collect();
+
+    Catalogue {
+        categories,
+        operations,
+    }
+}
@@ -0,0 +1,1220 @@
+use serde_json::{Map, Value};
+
+use crate::{utils::Delimiter, OperationError, Operations, Recipe, RecipeStep};
+
+/// How one positional CyberChef argument maps to the params of an operation.
+enum Arg {
+    /// Copied to the param as is.
+    Param(&'static str),
+    /// Copied to an optional param, exported as the value when the param is not set.
+    Default(&'static str, Literal),
+    /// Boolean stored negated, `false` when the param is not set.
+    Negated(&'static str),
+    /// One of `(CyberChef, crate)` names, `None` leaves the param out.
+    Choice(
+        &'static str,
+        &'static [(&'static str, Option<&'static str>)],
+    ),
+    /// `{ "option": format, "string": value }` setting the param and the param holding its format,
+    /// without a format param only UTF8 is accepted.
+    Toggle(&'static str, Option<&'static str>),
+    /// Delimiter written with escape sequences like `\n`, exported from the fallback param when not set.
+    Escaped(&'static str, Option<&'static str>),
+    /// "Ignore errors" of Fork and Subsection, stored as `on_error`.
+    IgnoreErrors,
+    /// Argument the operation always behaves as if it had this value.
+    Fixed(Literal),
+}
+
+#[derive(Clone, Copy)]
+enum Literal {
+    Bool(bool),
+    Number(u64),
+    Str(&'static str),
+}
+
+impl Literal {
+    fn value(self) -> Value {
+        match self {
+            Self::Bool(value) => Value::from(value),
+            Self::Number(value) => Value::from(value),
+            Self::Str(value) => Value::from(value),
+        }
+    }
+}
+
+struct Mapping {
+    op: Operations,
+    name: &'static str,
+    args: &'static [Arg],
+    /// Params CyberChef has no argument for, set on import and required on export.
+    fixed: &'static [(&'static str, Literal)],
+}
+
+const EN: &[(&str, Literal)] = &[("lang", Literal::Str("en"))];
+
+const HEX: &[(&str, Literal)] = &[("output_format", Literal::Str("hex"))];
+
+const DELIMITERS: &[(&str, Option<&str>)] = &[
+    ("Space", Some("Space")),
+    ("Comma", Some("Comma")),
+    ("Semi-colon", Some("Semi-colon")),
+    ("Colon", Some("Colon")),
+    ("Line feed", Some("Line feed")),
+    ("CRLF", Some("CRLF")),
+];
+
+const A1Z26_DELIMITERS: &[(&str, Option<&str>)] = &[
+    ("Space", Some("Space")),
+    ("Comma", Some("Comma")),
+    ("Semi-colon", Some("SemiColon")),
+    ("Colon", Some("Colon")),
+    ("Line feed", Some("LineFeed")),
+    ("CRLF", Some("CRLF")),
+];
+
+const BINARY_DELIMITERS: &[(&str, Option<&str>)] = &[
+    ("Space", Some("Space")),
+    ("Comma", Some("Comma")),
+    ("Semi-colon", Some("Semi-colon")),
+    ("Colon", Some("Colon")),
+    ("Line feed", Some("Line feed")),
+    ("CRLF", Some("CRLF")),
+    ("None", None),
+];
+
+const HEX_DELIMITERS: &[(&str, Option<&str>)] = &[
+    ("Auto", None),
+    ("Space", Some("Space")),
+    ("Comma", Some("Comma")),
+    ("Semi-colon", Some("Semi-colon")),
+    ("Colon", Some("Colon")),
+    ("Line feed", Some("Line feed")),
+    ("CRLF", Some("CRLF")),
+    ("None", None),
+];
+
+const BACON_ALPHABETS: &[(&str, Option<&str>)] = &[
+    ("Standard (I=J and U=V)", Some("Standard")),
+    ("Complete", Some("Complete")),
+];
+
+const BACON_TRANSLATIONS: &[(&str, Option<&str>)] = &[("0/1", Some("0/1")), ("A/B", Some("A/B"))];
+
+const BLAKE2_OUTPUTS: &[(&str, Option<&str>)] = &[
+    ("Hex", Some("hex")),
+    ("Base64", Some("base64")),
+    ("Raw", Some("raw")),
+    ("Raw", Some("uint8array")),
+];
+
+const RSA_SCHEMES: &[(&str, Option<&str>)] = &[
+    ("RSA-OAEP", Some("oaep")),
+    ("RSAES-PKCS1-V1_5", Some("pkcs1_v15")),
+];
+
+const RSA_DIGESTS: &[(&str, Option<&str>)] = &[
+    ("SHA-1", Some("sha1")),
+    ("SHA-256", Some("sha2_256")),
+    ("SHA-384", Some("sha2_384")),
+    ("SHA-512", Some("sha2_512")),
+];
+
+const KEY_FORMATS: &[(&str, &str)] = &[
+    ("Hex", "hex"),
+    ("Binary", "binary"),
+    ("Base64", "base64"),
+    ("UTF8", "utf8"),
+    ("Latin1", "latin1"),
+    ("Decimal", "decimal"),
+];
+
+const MAPPINGS: &[Mapping] = &[
+    Mapping {
+        op: Operations::A1Z26CipherDecode,
+        name: "A1Z26 Cipher Decode",
+        args: &[Arg::Choice("delimiter", A1Z26_DELIMITERS)],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::A1Z26CipherEncode,
+        name: "A1Z26 Cipher Encode",
+        args: &[Arg::Choice("delimiter", A1Z26_DELIMITERS)],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ADD,
+        name: "ADD",
+        args: &[Arg::Toggle("key", Some("key_format"))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::AddLineNumbers,
+        name: "Add line numbers",
+        args: &[],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Adler32CheckSum,
+        name: "Adler-32 Checksum",
+        args: &[],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::AffineCipherDecode,
+        name: "Affine Cipher Decode",
+        args: &[Arg::Param("a"), Arg::Param("b")],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::AffineCipherEncode,
+        name: "Affine Cipher Encode",
+        args: &[Arg::Param("a"), Arg::Param("b")],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::AnalyseHash,
+        name: "Analyse hash",
+        args: &[],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::AND,
+        name: "AND",
+        args: &[Arg::Toggle("key", Some("key_format"))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Argon2Compare,
+        name: "Argon2 compare",
+        args: &[Arg::Param("encoded_hash")],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Argon2,
+        name: "Argon2",
+        args: &[
+            Arg::Toggle("salt", None),
+            Arg::Param("iterations"),
+            Arg::Param("memory"),
+            Arg::Param("parallelism"),
+            Arg::Param("hash_length"),
+            Arg::Choice(
+                "argon2_type",
+                &[
+                    ("Argon2i", Some("Argon2i")),
+                    ("Argon2d", Some("Argon2d")),
+                    ("Argon2id", Some("Argon2id")),
+                ],
+            ),
+            Arg::Choice(
+                "output_format",
+                &[
+                    ("Encoded hash", Some("encoded")),
+                    ("Hex hash", Some("hex")),
+                    ("Raw hash", Some("raw")),
+                ],
+            ),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::AtbashCipher,
+        name: "Atbash Cipher",
+        args: &[],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::BaconCipherEncode,
+        name: "Bacon Cipher Encode",
+        args: &[
+            Arg::Choice("bacon_alphabet", BACON_ALPHABETS),
+            Arg::Choice("translation", BACON_TRANSLATIONS),
+            Arg::Param("keep_extra_character"),
+            Arg::Param("invert_translation"),
+        ],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::BaconCipherDecode,
+        name: "Bacon Cipher Decode",
+        args: &[
+            Arg::Choice("bacon_alphabet", BACON_ALPHABETS),
+            Arg::Choice("translation", BACON_TRANSLATIONS),
+            Arg::Param("invert_translation"),
+        ],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::BcryptCompare,
+        name: "Bcrypt compare",
+        args: &[Arg::Param("encoded_hash")],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Bcrypt,
+        name: "Bcrypt",
+        args: &[Arg::Param("rounds")],
+        fixed: &[("version", Literal::Str("2a"))],
+    },
+    Mapping {
+        op: Operations::BcryptParse,
+        name: "Bcrypt parse",
+        args: &[],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::BifidCipherDecode,
+        name: "Bifid Cipher Decode",
+        args: &[Arg::Param("keyword")],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::BifidCipherEncode,
+        name: "Bifid Cipher Encode",
+        args: &[Arg::Param("keyword")],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::Blake2b,
+        name: "BLAKE2b",
+        args: &[
+            Arg::Choice(
+                "size",
+                &[
+                    ("512", Some("512")),
+                    ("384", Some("384")),
+                    ("256", Some("256")),
+                    ("160", Some("160")),
+                    ("128", Some("128")),
+                ],
+            ),
+            Arg::Choice("output_format", BLAKE2_OUTPUTS),
+            Arg::Toggle("key", Some("key_format")),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Blake2s,
+        name: "BLAKE2s",
+        args: &[
+            Arg::Choice(
+                "size",
+                &[
+                    ("256", Some("256")),
+                    ("160", Some("160")),
+                    ("128", Some("128")),
+                ],
+            ),
+            Arg::Choice("output_format", BLAKE2_OUTPUTS),
+            Arg::Toggle("key", Some("key_format")),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ConditionalJump,
+        name: "Conditional Jump",
+        args: &[
+            Arg::Param("regex"),
+            Arg::Default("invert_condition", Literal::Bool(false)),
+            Arg::Param("label"),
+            Arg::Default("max_jumps", Literal::Number(10)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Filter,
+        name: "Filter",
+        args: &[
+            Arg::Choice("delimiter", DELIMITERS),
+            Arg::Param("regex"),
+            Arg::Param("invert_condition"),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Fork,
+        name: "Fork",
+        args: &[
+            Arg::Escaped("split_delimiter", None),
+            Arg::Escaped("merge_delimiter", Some("split_delimiter")),
+            Arg::IgnoreErrors,
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::FromBase64,
+        name: "From Base64",
+        args: &[
+            Arg::Default("alphabet", Literal::Str("A-Za-z0-9+/=")),
+            Arg::Default("remove_non_alphabetic_chars", Literal::Bool(false)),
+            Arg::Default("strict_mode", Literal::Bool(false)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::FromBase,
+        name: "From Base",
+        args: &[Arg::Param("radix")],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::FromBinary,
+        name: "From Binary",
+        args: &[
+            Arg::Choice("delimiter", BINARY_DELIMITERS),
+            Arg::Default("byte_length", Literal::Number(8)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::FromHex,
+        name: "From Hex",
+        args: &[Arg::Choice("delimiter", HEX_DELIMITERS)],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::HMAC,
+        name: "HMAC",
+        args: &[
+            Arg::Toggle("key", Some("key_format")),
+            Arg::Choice(
+                "hash_function",
+                &[
+                    ("MD2", Some("md2")),
+                    ("MD4", Some("md4")),
+                    ("MD5", Some("md5")),
+                    ("SHA1", Some("sha1")),
+                    ("SHA224", Some("sha224")),
+                    ("SHA256", Some("sha256")),
+                    ("SHA384", Some("sha384")),
+                    ("SHA512", Some("sha512")),
+                    ("SHA512/224", Some("sha512_224")),
+                    ("SHA512/256", Some("sha512_256")),
+                    ("RIPEMD128", Some("ripemd128")),
+                    ("RIPEMD160", Some("ripemd160")),
+                    ("RIPEMD256", Some("ripemd256")),
+                    ("RIPEMD320", Some("ripemd320")),
+                    ("Whirlpool", Some("whirlpool")),
+                ],
+            ),
+        ],
+        fixed: &[("output_format", Literal::Str("hex"))],
+    },
+    Mapping {
+        op: Operations::Jump,
+        name: "Jump",
+        args: &[
+            Arg::Param("label"),
+            Arg::Default("max_jumps", Literal::Number(10)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Label,
+        name: "Label",
+        args: &[Arg::Param("name")],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Magic,
+        name: "Magic",
+        args: &[
+            Arg::Default("depth", Literal::Number(3)),
+            Arg::Fixed(Literal::Bool(false)),
+            Arg::Fixed(Literal::Bool(false)),
+            Arg::Fixed(Literal::Str("")),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::MD2,
+        name: "MD2",
+        args: &[Arg::Fixed(Literal::Number(18))],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::MD4,
+        name: "MD4",
+        args: &[],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::MD5,
+        name: "MD5",
+        args: &[],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::Merge,
+        name: "Merge",
+        args: &[Arg::Fixed(Literal::Bool(true))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::Register,
+        name: "Register",
+        args: &[
+            Arg::Param("extractor"),
+            Arg::Default("case_insensitive", Literal::Bool(false)),
+            Arg::Default("multiline", Literal::Bool(false)),
+            Arg::Fixed(Literal::Bool(false)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ReverseString,
+        name: "Reverse",
+        args: &[Arg::Fixed(Literal::Str("Character"))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ROT13,
+        name: "ROT13",
+        args: &[
+            Arg::Default("rotate_lower", Literal::Bool(true)),
+            Arg::Default("rotate_upper", Literal::Bool(true)),
+            Arg::Default("rotate_numbers", Literal::Bool(false)),
+            Arg::Default("amount", Literal::Number(13)),
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ROT47,
+        name: "ROT47",
+        args: &[Arg::Default("amount", Literal::Number(47))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ROT8000,
+        name: "ROT8000",
+        args: &[],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::RSADecrypt,
+        name: "RSA Decrypt",
+        args: &[
+            Arg::Param("pem_key"),
+            Arg::Fixed(Literal::Str("")),
+            Arg::Choice("scheme", RSA_SCHEMES),
+            Arg::Choice("digest_alg", RSA_DIGESTS),
+        ],
+        fixed: &[("output_format", Literal::Str("raw"))],
+    },
+    Mapping {
+        op: Operations::RSAEncrypt,
+        name: "RSA Encrypt",
+        args: &[
+            Arg::Param("pub_key"),
+            Arg::Choice("scheme", RSA_SCHEMES),
+            Arg::Choice("digest_alg", RSA_DIGESTS),
+        ],
+        fixed: &[("output_format", Literal::Str("raw"))],
+    },
+    Mapping {
+        op: Operations::SHA1,
+        name: "SHA1",
+        args: &[Arg::Fixed(Literal::Number(80))],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::SHA2,
+        name: "SHA2",
+        args: &[
+            Arg::Choice(
+                "size",
+                &[
+                    ("512", Some("sha512")),
+                    ("384", Some("sha384")),
+                    ("256", Some("sha256")),
+                    ("224", Some("sha224")),
+                    ("512/256", Some("sha512_256")),
+                    ("512/224", Some("sha512_224")),
+                ],
+            ),
+            Arg::Fixed(Literal::Number(64)),
+            Arg::Fixed(Literal::Number(160)),
+        ],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::SHA3,
+        name: "SHA3",
+        args: &[Arg::Choice(
+            "size",
+            &[
+                ("512", Some("sha512")),
+                ("384", Some("sha384")),
+                ("256", Some("sha256")),
+                ("224", Some("sha224")),
+            ],
+        )],
+        fixed: HEX,
+    },
+    Mapping {
+        op: Operations::Subsection,
+        name: "Subsection",
+        args: &[
+            Arg::Param("regex"),
+            Arg::Negated("case_insensitive"),
+            Arg::Default("global", Literal::Bool(true)),
+            Arg::IgnoreErrors,
+        ],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ToBase64,
+        name: "To Base64",
+        args: &[Arg::Default("alphabet", Literal::Str("A-Za-z0-9+/="))],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::ToBase,
+        name: "To Base",
+        args: &[Arg::Param("radix")],
+        fixed: &[],
+    },
+    Mapping {
+        op: Operations::VigenereCipherDecode,
+        name: "Vigenère Decode",
+        args: &[Arg::Param("key")],
+        fixed: EN,
+    },
+    Mapping {
+        op: Operations::VigenereCipherEncode,
+        name: "Vigenère Encode",
+        args: &[Arg::Param("key")],
+        fixed: EN,
+    },
+];
+
+/// Recipes in the formats of [CyberChef](https://gchq.github.io/CyberChef/).
+/// The JSON format is a list of `{ "op": "From Base64", "args": ["A-Za-z0-9+/=", true, false] }`,
+/// the chef format is the same recipe as it appears in CyberChef urls,
+/// `From_Base64('A-Za-z0-9+/=',true,false)Reverse('Character')`.
+/// Disabled steps are left out, CyberChef operations this crate does not have and arguments
+/// it can't honour are errors naming them.
+impl Recipe {
+    pub fn from_cyberchef(recipe: &Value) -> Result<Self, OperationError> {
+        let Value::Array(steps) = recipe else {
+            return Err(not_a_recipe());
+        };
+
+        let mut parsed = Vec::with_capacity(steps.len());
+        for step in steps {
+            let (Some(op), args) = (
+                step.get("op").and_then(Value::as_str),
+                step.get("args")
+                    .cloned()
+                    .unwrap_or(Value::Array(Vec::new())),
+            ) else {
+                return Err(not_a_recipe());
+            };
+            let Value::Array(args) = args else {
+                return Err(not_a_recipe());
+            };
+            let disabled = step.get("disabled").and_then(Value::as_bool) == Some(true);
+            parsed.push((op.to_string(), args, disabled));
+        }
+
+        import(parsed)
+    }
+
+    /// Accepts the bare chef format, a `recipe=...` fragment or a whole CyberChef url.
+    pub fn from_chef_format(recipe: &str) -> Result<Self, OperationError> {
+        let recipe = match recipe
+            .split_once('#')
+            .map_or(recipe, |(_, fragment)| fragment)
+        {
+            fragment if fragment.contains("recipe=") => percent_decode(
+                fragment
+                    .split('&')
+                    .find_map(|part| part.strip_prefix("recipe="))
+                    .unwrap_or_default(),
+            ),
+            _ => recipe.to_string(),
+        };
+
+        import(ChefParser::new(&recipe).steps()?)
+    }
+
+    pub fn to_cyberchef(&self) -> Result<Value, OperationError> {
+        Ok(Value::Array(
+            export(self)?
+                .into_iter()
+                .map(|(name, args)| {
+                    let mut step = Map::new();
+                    step.insert("op".to_string(), Value::from(name));
+                    step.insert("args".to_string(), Value::Array(args));
+                    Value::Object(step)
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn to_chef_format(&self) -> Result<String, OperationError> {
+        Ok(export(self)?
+            .into_iter()
+            .map(|(name, args)| {
+                let args: Vec<String> = args.iter().map(chef_literal).collect();
+                format!("{}({})", name.replace(' ', "_"), args.join(","))
+            })
+            .collect())
+    }
+}
+
+fn import(steps: Vec<(String, Vec<Value>, bool)>) -> Result<Recipe, OperationError> {
+    let unsupported: Vec<(usize, &str)> = steps
+        .iter()
+        .enumerate()
+        .filter(|(_, (name, ..))| mapping_by_name(name).is_none())
+        .map(|(idx, (name, ..))| (idx, name.as_str()))
+        .collect();
+
+    if let Some(&(first, _)) = unsupported.first() {
+        let names = unsupported
+            .iter()
+            .map(|(_, name)| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let (en, ru) = match unsupported.len() {
+            1 => (
+                format!("CyberChef operation {names} is not supported."),
+                format!("Операция CyberChef {names} не поддерживается."),
+            ),
+            _ => (
+                format!("CyberChef operations {names} are not supported."),
+                format!("Операции CyberChef {names} не поддерживаются."),
+            ),
+        };
+        return Err(OperationError::invalid_parameter(
+            format!("recipe[{first}].op"),
+            en,
+            ru,
+        ));
+    }
+
+    let mut recipe = Vec::with_capacity(steps.len());
+    for (idx, (name, args, disabled)) in steps.into_iter().enumerate() {
+        if disabled {
+            continue;
+        }
+        let mapping = mapping_by_name(&name).expect("checked above");
+        recipe.push(RecipeStep::new(
+            mapping.op,
+            import_args(mapping, args, idx)?,
+        ));
+    }
+
+    Ok(Recipe::new(recipe))
+}
+
+fn import_args(mapping: &Mapping, args: Vec<Value>, step: usize) -> Result<Value, OperationError> {
+    let mut params = Map::new();
+    for (param, value) in mapping.fixed {
+        params.insert(param.to_string(), value.value());
+    }
+
+    for (idx, (arg, value)) in mapping.args.iter().zip(args).enumerate() {
+        let unsupported = |expected: String| {
+            OperationError::invalid_parameter(
+                format!("recipe[{step}].args[{idx}]"),
+                format!(
+                    "`{value}` is not supported for `{}`, expected {expected}.",
+                    mapping.name
+                ),
+                format!(
+                    "`{value}` не поддерживается для `{}`, ожидается {expected}.",
+                    mapping.name
+                ),
+            )
+        };
+
+        match arg {
+            Arg::Param(param) | Arg::Default(param, _) => {
+                params.insert(param.to_string(), value);
+            }
+            Arg::Negated(param) => {
+                let Some(value) = value.as_bool() else {
+                    return Err(unsupported("`true` or `false`".to_string()));
+                };
+                params.insert(param.to_string(), Value::from(!value));
+            }
+            Arg::Choice(param, choices) => {
+                let choice = choices
+                    .iter()
+                    .find(|(name, _)| value.as_str() == Some(name))
+                    .ok_or_else(|| unsupported(one_of(choices.iter().map(|(name, _)| *name))))?;
+                if let Some(choice) = choice.1 {
+                    params.insert(param.to_string(), Value::from(choice));
+                }
+            }
+            Arg::Toggle(param, format_param) => {
+                let (Some(format), Some(string)) = (
+                    value.get("option").and_then(Value::as_str),
+                    value.get("string").and_then(Value::as_str),
+                ) else {
+                    return Err(unsupported("`{ option, string }`".to_string()));
+                };
+                match format_param {
+                    Some(format_param) => {
+                        let format = KEY_FORMATS
+                            .iter()
+                            .find(|(name, _)| *name == format)
+                            .ok_or_else(|| {
+                                unsupported(one_of(KEY_FORMATS.iter().map(|(name, _)| *name)))
+                            })?;
+                        params.insert(param.to_string(), Value::from(string));
+                        params.insert(format_param.to_string(), Value::from(format.1));
+                    }
+                    None if format == "UTF8" => {
+                        params.insert(param.to_string(), Value::from(string));
+                    }
+                    None => return Err(unsupported("`UTF8`".to_string())),
+                }
+            }
+            Arg::Escaped(param, _) => {
+                let Some(delimiter) = value.as_str() else {
+                    return Err(unsupported("a string".to_string()));
+                };
+                params.insert(param.to_string(), Value::from(unescape(delimiter)));
+            }
+            Arg::IgnoreErrors => match value.as_bool() {
+                Some(true) => {
+                    params.insert("on_error".to_string(), Value::from("ignore"));
+                }
+                Some(false) => {}
+                None => return Err(unsupported("`true` or `false`".to_string())),
+            },
+            Arg::Fixed(expected) if value != expected.value() => {
+                return Err(unsupported(format!("`{}`", expected.value())));
+            }
+            Arg::Fixed(_) => {}
+        }
+    }
+
+    Ok(Value::Object(params))
+}
+
+fn export(recipe: &Recipe) -> Result<Vec<(&'static str, Vec<Value>)>, OperationError> {
+    recipe
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(idx, step)| {
+            let mapping = MAPPINGS
+                .iter()
+                .find(|mapping| mapping.op == step.op)
+                .ok_or_else(|| {
+                    OperationError::invalid_parameter(
+                        format!("recipe[{idx}].op"),
+                        format!("CyberChef has no `{}` operation.", step.op.name()),
+                        format!("В CyberChef нет операции `{}`.", step.op.name()),
+                    )
+                })?;
+            Ok((mapping.name, export_args(mapping, &step.params, idx)?))
+        })
+        .collect()
+}
+
+fn export_args(
+    mapping: &Mapping,
+    params: &Value,
+    step: usize,
+) -> Result<Vec<Value>, OperationError> {
+    let empty = Map::new();
+    let params = params.as_object().unwrap_or(&empty);
+    let param = |name: &str| params.get(name).filter(|value| !value.is_null());
+    let inexpressible = |name: &str| {
+        let value = param(name).cloned().unwrap_or(Value::Null);
+        OperationError::invalid_parameter(
+            format!("recipe[{step}].params.{name}"),
+            format!(
+                "`{name}` = `{value}` of `{}` can't be expressed in CyberChef.",
+                mapping.op.name()
+            ),
+            format!(
+                "`{name}` = `{value}` операции `{}` невозможно выразить в CyberChef.",
+                mapping.op.name()
+            ),
+        )
+    };
+
+    let mut used = Vec::new();
+    for (name, value) in mapping.fixed {
+        if param(name).is_some_and(|param| *param != value.value()) {
+            return Err(inexpressible(name));
+        }
+        used.push(*name);
+    }
+
+    let mut args = Vec::with_capacity(mapping.args.len());
+    for arg in mapping.args {
+        let value = match arg {
+            Arg::Param(name) => {
+                used.push(name);
+                param(name).cloned().unwrap_or(Value::Null)
+            }
+            Arg::Default(name, default) => {
+                used.push(name);
+                param(name).cloned().unwrap_or_else(|| default.value())
+            }
+            Arg::Negated(name) => {
+                used.push(name);
+                match param(name) {
+                    Some(Value::Bool(value)) => Value::from(!value),
+                    Some(_) => return Err(inexpressible(name)),
+                    None => Value::from(true),
+                }
+            }
+            Arg::Choice(name, choices) => {
+                used.push(name);
+                let value = param(name).and_then(Value::as_str);
+                let choice = choices
+                    .iter()
+                    .find(|(_, choice)| *choice == value)
+                    .ok_or_else(|| inexpressible(name))?;
+                Value::from(choice.0)
+            }
+            Arg::Toggle(name, format_param) => {
+                used.push(name);
+                let format = match format_param {
+                    Some(format_param) => {
+                        used.push(format_param);
+                        match param(format_param).and_then(Value::as_str) {
+                            Some(format) => {
+                                KEY_FORMATS
+                                    .iter()
+                                    .find(|(_, ours)| *ours == format)
+                                    .ok_or_else(|| inexpressible(format_param))?
+                                    .0
+                            }
+                            None => "UTF8",
+                        }
+                    }
+                    None => "UTF8",
+                };
+                let mut toggle = Map::new();
+                toggle.insert("option".to_string(), Value::from(format));
+                toggle.insert(
+                    "string".to_string(),
+                    param(name).cloned().unwrap_or(Value::from("")),
+                );
+                Value::Object(toggle)
+            }
+            Arg::Escaped(name, fallback) => {
+                used.push(name);
+                let value = param(name)
+                    .or_else(|| fallback.and_then(param))
+                    .ok_or_else(|| inexpressible(name))?;
+                let delimiter: Delimiter =
+                    serde_json::from_value(value.clone()).map_err(|_| inexpressible(name))?;
+                Value::from(escape(delimiter.as_str()))
+            }
+            Arg::IgnoreErrors => {
+                used.push("on_error");
+                match param("on_error").and_then(Value::as_str) {
+                    None | Some("fail_fast") => Value::from(false),
+                    Some("ignore") => Value::from(true),
+                    Some(_) => return Err(inexpressible("on_error")),
+                }
+            }
+            Arg::Fixed(value) => value.value(),
+        };
+        args.push(value);
+    }
+
+    if let Some(name) = params
+        .iter()
+        .find(|(name, value)| !value.is_null() && !used.contains(&name.as_str()))
+        .map(|(name, _)| name)
+    {
+        return Err(inexpressible(name));
+    }
+
+    Ok(args)
+}
+
+fn mapping_by_name(name: &str) -> Option<&'static Mapping> {
+    MAPPINGS.iter().find(|mapping| mapping.name == name)
+}
+
+fn one_of<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let names: Vec<String> = names.map(|name| format!("`{name}`")).collect();
+    format!("one of {}", names.join(", "))
+}
+
+fn not_a_recipe() -> OperationError {
+    OperationError::invalid_parameter(
+        "recipe",
+        "CyberChef recipe must be a list of `{ \"op\": name, \"args\": [...] }`.",
+        "Рецепт CyberChef должен быть списком `{ \"op\": name, \"args\": [...] }`.",
+    )
+}
+
+fn escape(delimiter: &str) -> String {
+    delimiter
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+fn unescape(delimiter: &str) -> String {
+    let mut output = String::with_capacity(delimiter.len());
+    let mut chars = delimiter.chars();
+    while let Some(char) = chars.next() {
+        match (char, chars.clone().next()) {
+            ('\\', Some(escaped @ ('n' | 'r' | 't' | '\\'))) => {
+                chars.next();
+                output.push(match escaped {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    _ => '\\',
+                });
+            }
+            (char, _) => output.push(char),
+        }
+    }
+    output
+}
+
+fn percent_decode(string: &str) -> String {
+    let bytes = string.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let decoded = match bytes[idx] {
+            b'%' => bytes
+                .get(idx + 1..idx + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match decoded {
+            Some(byte) => {
+                output.push(byte);
+                idx += 3;
+            }
+            None => {
+                output.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+/// Argument as CyberChef writes it in the chef format.
+fn chef_literal(value: &Value) -> String {
+    match value {
+        Value::String(string) => format!(
+            "'{}'",
+            string
+                .replace('\\', "\\\\")
+                .replace('\'', "\\'")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+                .replace('\t', "\\t")
+        ),
+        Value::Array(values) => {
+            let values: Vec<String> = values.iter().map(chef_literal).collect();
+            format!("[{}]", values.join(","))
+        }
+        Value::Object(map) => {
+            let fields: Vec<String> = map
+                .iter()
+                .map(|(key, value)| format!("'{key}':{}", chef_literal(value)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        value => value.to_string(),
+    }
+}
+
+/// Parses `Name(arg,...)` steps whose arguments are javascript literals.
+struct ChefParser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> ChefParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    fn steps(mut self) -> Result<Vec<(String, Vec<Value>, bool)>, OperationError> {
+        let mut steps = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.rest().is_empty() {
+                return Ok(steps);
+            }
+
+            let name_len = self.rest().find('(').ok_or_else(|| self.error("`(`"))?;
+            let name = self.rest()[..name_len].trim().replace('_', " ");
+            self.position += name_len + 1;
+
+            let args = self.list(')')?;
+            let mut disabled = false;
+            while let Some(flag) = ["/disabled", "/breakpoint"]
+                .into_iter()
+                .find(|flag| self.rest().starts_with(flag))
+            {
+                disabled |= flag == "/disabled";
+                self.position += flag.len();
+            }
+
+            steps.push((name, args, disabled));
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position += self.rest().len() - self.rest().trim_start().len();
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        let eaten = self.peek() == Some(expected);
+        if eaten {
+            self.position += expected.len_utf8();
+        }
+        eaten
+    }
+
+    /// Comma separated values up to `end`, the opening bracket is already consumed.
+    fn list(&mut self, end: char) -> Result<Vec<Value>, OperationError> {
+        let mut values = Vec::new();
+        if self.eat(end) {
+            return Ok(values);
+        }
+        loop {
+            values.push(self.value()?);
+            if self.eat(end) {
+                return Ok(values);
+            }
+            if !self.eat(',') {
+                return Err(self.error(&format!("`,` or `{end}`")));
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, OperationError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) => {
+                self.position += 1;
+                self.string(quote).map(Value::String)
+            }
+            Some('[') => {
+                self.position += 1;
+                self.list(']').map(Value::Array)
+            }
+            Some('{') => {
+                self.position += 1;
+                self.object()
+            }
+            _ => {
+                let len = self
+                    .rest()
+                    .find(|char: char| {
+                        matches!(char, ',' | ')' | ']' | '}') || char.is_whitespace()
+                    })
+                    .unwrap_or(self.rest().len());
+                let literal = &self.rest()[..len];
+                let value = match literal {
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    "null" => Value::Null,
+                    literal => serde_json::from_str::<serde_json::Number>(literal)
+                        .map(Value::Number)
+                        .map_err(|_| self.error("a value"))?,
+                };
+                self.position += len;
+                Ok(value)
+            }
+        }
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, OperationError> {
+        let mut output = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((idx, char)) = chars.next() {
+            match char {
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => output.push('\n'),
+                    Some((_, 'r')) => output.push('\r'),
+                    Some((_, 't')) => output.push('\t'),
+                    Some((_, escaped)) => output.push(escaped),
+                    None => break,
+                },
+                char if char == quote => {
+                    self.position += idx + 1;
+                    return Ok(output);
+                }
+                char => output.push(char),
+            }
+        }
+        self.position = self.source.len();
+        Err(self.error(&format!("closing `{quote}`")))
+    }
+
+    fn object(&mut self) -> Result<Value, OperationError> {
+        let mut map = Map::new();
+        if self.eat('}') {
+            return Ok(Value::Object(map));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = match self.peek() {
+                Some(quote @ ('\'' | '"')) => {
+                    self.position += 1;
+                    self.string(quote)?
+                }
+                _ => {
+                    let len = self.rest().find(':').ok_or_else(|| self.error("`:`"))?;
+                    let key = self.rest()[..len].trim().to_string();
+                    self.position += len;
+                    key
+                }
+            };
+            if !self.eat(':') {
+                return Err(self.error("`:`"));
+            }
+            map.insert(key, self.value()?);
+            if self.eat('}') {
+                return Ok(Value::Object(map));
+            }
+            if !self.eat(',') {
+                return Err(self.error("`,` or `}`"));
+            }
+        }
+    }
+
+    fn error(&self, expected: &str) -> OperationError {
+        OperationError::invalid_parameter(
+            "recipe",
+            format!(
+                "Invalid chef format at position {}, expected {expected}.",
+                self.position
+            ),
+            format!(
+                "Некорректный формат chef в позиции {}, ожидается {expected}.",
+                self.position
+            ),
+        )
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    decode_input,
+    recipe::{bake_step, RecipeState},
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, OperationRequest, Operations, Recipe,
+};
+
+/// One executed step of a debugged recipe.
+#[derive(Serialize, Debug, Clone)]
+pub struct StepTrace {
+    /// Index of the step in the recipe.
+    pub step: usize,
+    pub op: Operations,
+    pub input: DataRepresentation,
+    /// `None` when the step failed.
+    pub output: Option<DataRepresentation>,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+    pub error: Option<OperationError>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DebugStatus {
+    /// Every step ran, `state.data` is the output of the recipe.
+    Finished,
+    /// Stopped after a breakpoint, `state` resumes at the following step.
+    Paused,
+    /// The last traced step failed, `state` retries it.
+    Failed,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RecipeDebug {
+    pub status: DebugStatus,
+    pub state: RecipeState,
+    pub trace: Vec<StepTrace>,
+}
+
+impl Recipe {
+    /// Runs the recipe from `state` recording every step and stops after any step whose
+    /// index is in `breakpoints`, on the first error or at the end of the recipe.
+    /// A Fork or Subsection is traced as one step together with its body and Merge,
+    /// a breakpoint on any of them stops after the whole block.
+    /// ``` rust
+    /// let paused = recipe.debug(RecipeState::new(input), &[1]);
+    /// let step = paused.state.next_step;
+    /// let next = recipe.debug(paused.state, &[step]);
+    /// ```
+    pub fn debug(&self, mut state: RecipeState, breakpoints: &[usize]) -> RecipeDebug {
+        let mut trace = Vec::new();
+
+        while state.next_step < self.steps.len() {
+            let (step, op) = (state.next_step, self.steps[state.next_step].op);
+            let input = state.data.clone();
+            let started = Instant::now();
+            let result = bake_step(&self.steps, 0, &mut state, &mut Vec::new());
+            let elapsed = started.elapsed();
+
+            if let Err(err) = result {
+                state.data = input.clone();
+                trace.push(StepTrace {
+                    step,
+                    op,
+                    input,
+                    output: None,
+                    elapsed,
+                    error: Some(err),
+                });
+                return RecipeDebug {
+                    status: DebugStatus::Failed,
+                    state,
+                    trace,
+                };
+            }
+
+            trace.push(StepTrace {
+                step,
+                op,
+                input,
+                output: Some(state.data.clone()),
+                elapsed,
+                error: None,
+            });
+
+            let last = match op {
+                Operations::Fork | Operations::Subsection => state.next_step.max(step + 1) - 1,
+                _ => step,
+            };
+            if breakpoints.iter().any(|idx| (step..=last).contains(idx)) {
+                return RecipeDebug {
+                    status: DebugStatus::Paused,
+                    state,
+                    trace,
+                };
+            }
+        }
+
+        RecipeDebug {
+            status: DebugStatus::Finished,
+            state,
+            trace,
+        }
+    }
+}
+
+fn serialize_millis<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(elapsed.as_secs_f64() * 1000.0)
+}
+
+impl Operation<'_, DeserializeMeDaddy, RecipeDebug> for DebugRecipe {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<RecipeDebug, OperationError> {
+        let state = match (request.state, request.input) {
+            (Some(state), _) => state,
+            (None, Some(input)) => RecipeState::new(input),
+            (None, None) => {
+                return Err(OperationError::MissingField {
+                    param: "input".to_string(),
+                })
+            }
+        };
+
+        Ok(request.recipe.debug(state, &request.breakpoints))
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct DeserializeMeDaddy {
+    input: Option<DataRepresentation>,
+    input_format: Option<SupportedFormats>,
+    state: Option<RecipeState>,
+    recipe: Recipe,
+    #[serde(default)]
+    breakpoints: Vec<usize>,
+}
+
+impl OperationRequest for DeserializeMeDaddy {
+    fn set_input(&mut self, input: DataRepresentation) {
+        self.input = Some(input);
+    }
+
+    fn decode_input(&mut self) -> Result<(), OperationError> {
+        if let Some(format) = self.input_format.take() {
+            self.input = self
+                .input
+                .take()
+                .map(|input| decode_input(input, &format))
+                .transpose()?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a recipe step by step, sent as json payload with this structure
+/// ``` json
+/// {
+///     "input": string,
+///     "input_format": Option<SupportedFormats>,
+///     "state": Option<RecipeState>,
+///     "recipe": [
+///         { "op": Operations, "params": object }
+///     ],
+///     "breakpoints": Option<[usize]>
+/// }
+/// ```
+/// `state` is the `state` of an earlier answer and resumes the run, `input` is then ignored.
+/// Answer contains the `status` ("finished", "paused" or "failed"), the `state` to resume from
+/// and a `trace` with the `step` index, `op`, `input`, `output`, `elapsed_ms` and `error` of every executed step.
+pub struct DebugRecipe;
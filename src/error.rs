@@ -0,0 +1,239 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+/// Human readable text of an error in every language the api speaks.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorMessage {
+    pub en: String,
+    pub ru: String,
+}
+
+impl ErrorMessage {
+    pub fn new(en: impl Into<String>, ru: impl Into<String>) -> Self {
+        Self {
+            en: en.into(),
+            ru: ru.into(),
+        }
+    }
+}
+
+/// Error returned by every operation.
+/// Serializes to a stable shape the UI can rely on
+/// ``` json
+/// {
+///     "code": "invalid_parameter",
+///     "param": "params.rounds",
+///     "message": {
+///         "en": "Invalid value of `params.rounds`: The number must be `<= 31`.",
+///         "ru": "Недопустимое значение параметра `params.rounds`: The number must be `<= 31`."
+///     }
+/// }
+/// ```
+/// `param` is the path to the offending field of the request or `null`,
+/// errors of a recipe step additionally contain the 1-based `step` number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperationError {
+    InvalidJson {
+        details: String,
+    },
+    MissingField {
+        param: String,
+    },
+    InvalidParameter {
+        param: String,
+        message: ErrorMessage,
+    },
+    InvalidInputEncoding {
+        message: ErrorMessage,
+    },
+    InvalidInput {
+        message: ErrorMessage,
+    },
+    CryptoFailure {
+        details: String,
+    },
+    ResourceLimitExceeded {
+        param: String,
+        message: ErrorMessage,
+    },
+    UnknownOperation {
+        name: String,
+    },
+    Internal {
+        details: String,
+    },
+    RecipeStep {
+        step: usize,
+        op: &'static str,
+        source: Box<OperationError>,
+    },
+}
+
+impl OperationError {
+    pub fn invalid_parameter(
+        param: impl Into<String>,
+        en: impl Into<String>,
+        ru: impl Into<String>,
+    ) -> Self {
+        Self::InvalidParameter {
+            param: param.into(),
+            message: ErrorMessage::new(en, ru),
+        }
+    }
+
+    pub fn invalid_input_encoding(en: impl Into<String>, ru: impl Into<String>) -> Self {
+        Self::InvalidInputEncoding {
+            message: ErrorMessage::new(en, ru),
+        }
+    }
+
+    pub fn invalid_input(en: impl Into<String>, ru: impl Into<String>) -> Self {
+        Self::InvalidInput {
+            message: ErrorMessage::new(en, ru),
+        }
+    }
+
+    pub fn crypto_failure(details: impl Display) -> Self {
+        Self::CryptoFailure {
+            details: details.to_string(),
+        }
+    }
+
+    pub fn resource_limit_exceeded(
+        param: impl Into<String>,
+        en: impl Into<String>,
+        ru: impl Into<String>,
+    ) -> Self {
+        Self::ResourceLimitExceeded {
+            param: param.into(),
+            message: ErrorMessage::new(en, ru),
+        }
+    }
+
+    /// Maps errors of a `serde_valid::Validate` struct found at `prefix` (e.g. `params`).
+    /// The detail of serde_valid is not translated, both messages carry it as is.
+    pub fn from_validation(prefix: &str, errors: serde_valid::validation::Errors) -> Self {
+        use serde_valid::validation::Errors;
+
+        let (param, errors) = match errors {
+            Errors::Object(object) => match object.properties.into_iter().next() {
+                Some((field, errors)) => {
+                    (format!("{prefix}.{field}"), serde_json::to_value(errors))
+                }
+                None => (prefix.to_string(), serde_json::to_value(object.errors)),
+            },
+            errors => (prefix.to_string(), serde_json::to_value(errors)),
+        };
+        match errors.ok().as_ref().and_then(first_message) {
+            Some(details) => Self::invalid_parameter(
+                &param,
+                format!("Invalid value of `{param}`: {details}"),
+                format!("Недопустимое значение параметра `{param}`: {details}"),
+            ),
+            None => Self::invalid_parameter(
+                &param,
+                format!("Invalid value of `{param}`."),
+                format!("Недопустимое значение параметра `{param}`."),
+            ),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidJson { .. } => "invalid_json",
+            Self::MissingField { .. } => "missing_field",
+            Self::InvalidParameter { .. } => "invalid_parameter",
+            Self::InvalidInputEncoding { .. } => "invalid_input_encoding",
+            Self::InvalidInput { .. } => "invalid_input",
+            Self::CryptoFailure { .. } => "crypto_failure",
+            Self::ResourceLimitExceeded { .. } => "resource_limit_exceeded",
+            Self::UnknownOperation { .. } => "unknown_operation",
+            Self::Internal { .. } => "internal_error",
+            Self::RecipeStep { source, .. } => source.code(),
+        }
+    }
+
+    /// Path to the field of the request that caused the error.
+    pub fn param(&self) -> Option<&str> {
+        match self {
+            Self::MissingField { param }
+            | Self::InvalidParameter { param, .. }
+            | Self::ResourceLimitExceeded { param, .. } => Some(param),
+            Self::InvalidInputEncoding { .. } | Self::InvalidInput { .. } => Some("input"),
+            Self::RecipeStep { source, .. } => source.param(),
+            _ => None,
+        }
+    }
+
+    pub fn message(&self) -> ErrorMessage {
+        match self {
+            Self::InvalidJson { details } => ErrorMessage::new(
+                format!("Invalid JSON: {details}."),
+                format!("Некорректный JSON: {details}."),
+            ),
+            Self::MissingField { param } => ErrorMessage::new(
+                format!("Missing field `{param}`."),
+                format!("Отсутствует обязательное поле `{param}`."),
+            ),
+            Self::InvalidParameter { message, .. }
+            | Self::InvalidInputEncoding { message }
+            | Self::InvalidInput { message }
+            | Self::ResourceLimitExceeded { message, .. } => message.clone(),
+            Self::CryptoFailure { details } => ErrorMessage::new(
+                format!("Cryptographic operation failed: {details}."),
+                format!("Ошибка криптографической операции: {details}."),
+            ),
+            Self::UnknownOperation { name } => ErrorMessage::new(
+                format!("Unknown operation `{name}`."),
+                format!("Неизвестная операция `{name}`."),
+            ),
+            Self::Internal { details } => ErrorMessage::new(
+                format!("Internal error: {details}."),
+                format!("Внутренняя ошибка: {details}."),
+            ),
+            Self::RecipeStep { step, op, source } => {
+                let ErrorMessage { en, ru } = source.message();
+                ErrorMessage::new(
+                    format!("Step {step} ({op}): {en}"),
+                    format!("Шаг {step} ({op}): {ru}"),
+                )
+            }
+        }
+    }
+}
+
+fn first_message(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(message) => Some(message.clone()),
+        serde_json::Value::Array(values) => values.iter().find_map(first_message),
+        serde_json::Value::Object(map) => map.values().find_map(first_message),
+        _ => None,
+    }
+}
+
+impl Display for OperationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message().en)
+    }
+}
+
+impl std::error::Error for OperationError {}
+
+impl Serialize for OperationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let step = match self {
+            Self::RecipeStep { step, .. } => Some(step),
+            _ => None,
+        };
+
+        let mut state =
+            serializer.serialize_struct("OperationError", 3 + step.is_some() as usize)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("param", &self.param())?;
+        state.serialize_field("message", &self.message())?;
+        if let Some(step) = step {
+            state.serialize_field("step", step)?;
+        }
+        state.end()
+    }
+}
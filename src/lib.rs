@@ -1,90 +1,282 @@
 #![allow(dead_code)]
 
+mod batch;
+mod catalogue;
+mod cyberchef;
+mod debug;
+mod error;
 mod libs;
 mod macros;
 mod operations;
+mod policy;
+mod recipe;
+#[cfg(feature = "server")]
+mod server;
 mod traits;
 mod utils;
 
+pub use batch::Batch;
+pub use catalogue::{catalogue, Catalogue, Category, CategoryEntry, OperationEntry};
+pub use debug::{DebugRecipe, DebugStatus, RecipeDebug, StepTrace};
+pub use error::{ErrorMessage, OperationError};
+pub use libs::alphabet::{Alphabet, CustomAlphabet, Language};
+pub use libs::hashing::{hash_reader, HashAlgorithm, Hasher};
+pub use libs::scoring::Candidate;
 pub use operations::*;
-use serde::{Deserialize, Serialize};
-use traits::StringTrait;
+pub use policy::{CancellationToken, ExecutionPolicy};
+pub use recipe::{Recipe, RecipeOutput, RecipeState, RecipeStep, RunRecipe};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(feature = "server")]
+pub use server::{router, DEFAULT_MAX_REQUEST_SIZE};
+use std::any::Any;
+use utils::convert_to_byte_array;
+pub use utils::{
+    DataRepresentation, DataRepresentationInput, File, OutputEncoding, OutputEncodingOptions,
+    SupportedFormats, SupportedLanguages, SupportedOutputFormat,
+};
 
-pub fn run_operations<'a, I, O>(
-    operations: impl Operation<'a, I, O>,
+pub fn run_operations<I, O>(
+    operations: impl for<'a> Operation<'a, I, O>,
     request: &str,
-) -> Result<O, String>
+) -> Result<O, OperationError>
 where
-    I: Deserialize<'a>,
+    I: DeserializeOwned + OperationRequest,
     O: Serialize,
 {
     operations.do_black_magic(request)
 }
 
+/// Runs the operation registered under `name` and returns the serialized answer,
+/// `{ "Ok": ... }` or `{ "Err": ... }`.
+pub fn run_operation(name: &str, request: &str) -> String {
+    let result = name
+        .parse::<Operations>()
+        .and_then(|operation| operation.run_request(request));
+    serde_json::to_string(&result).unwrap()
+}
+
 pub trait Operation<'a, I, O>
 where
-    I: Deserialize<'a>,
+    I: Deserialize<'a> + OperationRequest,
     O: Serialize,
 {
-    fn do_black_magic(&self, request: &str) -> Result<O, String>;
-    fn validate(&self, request: &'a str) -> Result<I, String> {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::String;
+
+    fn do_black_magic(&self, request: &'a str) -> Result<O, OperationError> {
+        self.run(self.validate(request)?)
+    }
+
+    /// Runs the operation on an already validated request.
+    fn run(&self, request: I) -> Result<O, OperationError>;
+
+    fn validate(&self, request: &'a str) -> Result<I, OperationError> {
         self.deserialize(request)
     }
 
-    fn deserialize(&self, request: &'a str) -> Result<I, String> {
-        serde_json::from_str(request).map_err(|err| match err.to_string() {
-            err if err.starts_with("unknown")
-                || err.starts_with("missing")
-                || err.starts_with("invalid") =>
-            {
-                err.split(" at line ")
-                    .next()
-                    .unwrap()
-                    .to_string()
-                    .capitalize()
-                    + "."
-            }
-            err => err.capitalize() + ".",
-        })
+    fn deserialize(&self, request: &'a str) -> Result<I, OperationError> {
+        let deserializer = &mut serde_json::Deserializer::from_str(request);
+        let mut request: I =
+            serde_path_to_error::deserialize(deserializer).map_err(request_error)?;
+        request.decode_input()?;
+        Ok(request)
+    }
+}
+
+/// Deserialized request of an operation, its `input` can be replaced to reuse the params.
+pub trait OperationRequest: Clone {
+    fn set_input(&mut self, input: DataRepresentation);
+
+    /// Decodes the `input` with the `input_format` of the request, see [`decode_input`].
+    fn decode_input(&mut self) -> Result<(), OperationError>;
+}
+
+/// Classifies a deserialization error by its category and the path of the field it happened at.
+fn request_error(err: serde_path_to_error::Error<serde_json::Error>) -> OperationError {
+    let path = match err.path().to_string() {
+        path if path == "." => String::new(),
+        path => path,
+    };
+    let err = err.into_inner();
+
+    let details = err.to_string();
+    let details = details
+        .strip_suffix(&format!(" at line {} column {}", err.line(), err.column()))
+        .unwrap_or(&details)
+        .to_string();
+
+    if !err.is_data() {
+        return OperationError::InvalidJson { details };
+    }
+
+    if let Some(field) = details
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        let param = match path.is_empty() {
+            true => field.to_string(),
+            false => format!("{path}.{field}"),
+        };
+        return OperationError::MissingField { param };
+    }
+
+    if path == "input" || path.starts_with("input.") || path.starts_with("input[") {
+        return OperationError::invalid_input_encoding(
+            format!("Unsupported input: {details}."),
+            format!("Неподдерживаемый формат входных данных: {details}."),
+        );
     }
+
+    let param = match path.is_empty() {
+        true => "params".to_string(),
+        false => path,
+    };
+    OperationError::invalid_parameter(
+        &param,
+        format!("Invalid value of `{param}`: {details}."),
+        format!("Недопустимое значение параметра `{param}`: {details}."),
+    )
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub enum Operations {
-    A1Z26CipherDecode,
-    A1Z26CipherEncode,
-    Adler32CheckSum,
-    AffineCipherDecode,
-    AffineCipherEncode,
-    AnalyseHash,
-    Argon2Compare,
-    Argon2,
-    AtbashCipher,
-    BaconCipherEncode,
-    BaconCipherDecode,
-    BcryptCompare,
-    Bcrypt,
-    BcryptParse,
-    BifidCipherEncode,
-    Blake2b,
-    Blake2s,
-    FromBase64,
-    FromBase,
-    HMAC,
-    MD2,
-    MD4,
-    MD5,
-    RSADecrypt,
-    RSAEncrypt,
-    SHA1,
-    SHA2,
-    SHA3,
-    ToBase64,
-    ToBase,
-    VigenereCipherDecode,
-    VigenereCipherEncode,
+fn run_to_value<I, O>(
+    operation: impl for<'a> Operation<'a, I, O>,
+    request: &str,
+) -> Result<Value, OperationError>
+where
+    I: DeserializeOwned + OperationRequest,
+    O: Serialize,
+{
+    policy::checkpoint()?;
+    check_request_input(request)?;
+
+    let output = operation.do_black_magic(request)?;
+    Ok(serde_json::to_value(output).expect("operation output is serializable"))
+}
+
+fn run_dish<I, O, T>(
+    operation: T,
+    input: DataRepresentation,
+    params: Option<&str>,
+) -> Result<DataRepresentation, OperationError>
+where
+    T: for<'a> Operation<'a, I, O>,
+    I: DeserializeOwned + OperationRequest,
+    O: Serialize,
+{
+    policy::checkpoint()?;
+    let input = input.convert(<T as Operation<'_, I, O>>::INPUT_TYPE)?;
+    policy::check_input_size(input.size())?;
+
+    let output = operation.do_black_magic(&dish_request(&input, params))?;
+    Ok(dish_output(output))
+}
+
+/// Same as [`run_dish`] with a request [`parse_params`] made, only its `input` is replaced.
+fn run_dish_with<I, O, T>(
+    operation: T,
+    input: DataRepresentation,
+    params: &ParsedParams,
+) -> Result<DataRepresentation, OperationError>
+where
+    T: for<'a> Operation<'a, I, O>,
+    I: DeserializeOwned + OperationRequest + 'static,
+    O: Serialize,
+{
+    policy::checkpoint()?;
+    let input = input.convert(<T as Operation<'_, I, O>>::INPUT_TYPE)?;
+    policy::check_input_size(input.size())?;
+
+    let mut request = params
+        .downcast_ref::<I>()
+        .expect("params are parsed by the same operation")
+        .clone();
+    request.set_input(input);
+
+    let output = operation.run(request)?;
+    Ok(dish_output(output))
+}
+
+fn dish_output(output: impl Serialize) -> DataRepresentation {
+    serde_json::to_value(output)
+        .expect("operation output is serializable")
+        .into()
+}
+
+/// Request of an operation with an empty input, validated and ready for [`run_dish_with`].
+pub(crate) type ParsedParams = Box<dyn Any + Send + Sync>;
+
+fn parse_params<I, O>(
+    operation: impl for<'a> Operation<'a, I, O>,
+    params: Option<&str>,
+) -> Result<ParsedParams, OperationError>
+where
+    I: DeserializeOwned + OperationRequest + Send + Sync + 'static,
+    O: Serialize,
+{
+    let request = dish_request(&DataRepresentation::String(String::new()), params);
+    Ok(Box::new(operation.validate(&request)?))
+}
+
+/// Requests may send a text `input` in another encoding and name it in `input_format`,
+/// one of "utf8", "hex", "base64", "binary", "latin1" or "decimal".
+/// The input is decoded to bytes before the operation sees it.
+pub fn decode_input(
+    input: DataRepresentation,
+    format: &SupportedFormats,
+) -> Result<DataRepresentation, OperationError> {
+    let DataRepresentation::String(input) = input else {
+        return Err(OperationError::invalid_input_encoding(
+            "`input_format` needs a text `input`.",
+            "Для `input_format` нужен текстовый `input`.",
+        ));
+    };
+
+    let input = convert_to_byte_array(&input, format).map_err(|err| {
+        let err = err.trim_end_matches('.');
+        OperationError::invalid_input_encoding(
+            format!("Input is not valid {}: {err}.", format.as_str()),
+            format!(
+                "Входные данные не соответствуют формату {}: {err}.",
+                format.as_str()
+            ),
+        )
+    })?;
+
+    Ok(DataRepresentation::from_bytes(input))
+}
+
+/// Checks the size of the `input` of a raw request against the running policy.
+/// A request that does not parse is left to the operation to report.
+fn check_request_input(request: &str) -> Result<(), OperationError> {
+    #[derive(Deserialize)]
+    struct Request {
+        input: DataRepresentation,
+    }
+
+    if policy::limits_input() {
+        if let Ok(request) = serde_json::from_str::<Request>(request) {
+            policy::check_input_size(request.input.size())?;
+        }
+    }
+    Ok(())
+}
+
+/// Params as they are sent to an operation, left out when null.
+fn dish_params(params: &Value) -> Option<String> {
+    (!params.is_null()).then(|| params.to_string())
+}
+
+/// The /api/{name} payload for `input` and already serialized `params`.
+fn dish_request(input: &DataRepresentation, params: Option<&str>) -> String {
+    let input = serde_json::to_string(input).expect("input is serializable");
+    match params {
+        Some(params) => format!(r#"{{"input":{input},"params":{params}}}"#),
+        None => format!(r#"{{"input":{input}}}"#),
+    }
 }
 
+/// Answer of HMAC, BLAKE2 and RSA when `output_format` is one of "hex", "base64" or "uint8array",
+/// see [`OutputEncoding::encode_tagged`].
 #[derive(Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
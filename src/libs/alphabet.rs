@@ -0,0 +1,238 @@
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{utils::SupportedLanguages, OperationError};
+
+/// Characters filling the rest of a Polybius square after the letters.
+const POLYBIUS_PADDING: &str = ",.-=0123456789";
+const MAX_CUSTOM_LENGTH: usize = 128;
+/// Characters besides the letters [`Alphabet::validate`] accepts.
+static OTHER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[\p{P}\s\d]$").unwrap());
+
+/// `lang` of classical ciphers, a built-in language or a custom alphabet.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(
+    untagged,
+    expecting = "one of `en`, `ru`, `ru_with_yo`, `de`, `uk`, `el`, `latin_digits` or a custom alphabet"
+)]
+pub enum Language {
+    Builtin(SupportedLanguages),
+    Custom(CustomAlphabet),
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::Builtin(SupportedLanguages::EN)
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct CustomAlphabet {
+    /// Letters in alphabetical order.
+    pub lower: String,
+    /// Uppercase letters in the same order, `lower` uppercased when missing.
+    pub upper: Option<String>,
+}
+
+impl Language {
+    pub fn alphabet(&self) -> Result<Alphabet, OperationError> {
+        match self {
+            Self::Builtin(lang) => Ok(Alphabet::from(*lang)),
+            Self::Custom(custom) => custom.alphabet(),
+        }
+    }
+}
+
+impl CustomAlphabet {
+    fn alphabet(&self) -> Result<Alphabet, OperationError> {
+        let lower = self.lower.chars().collect_vec();
+        if !(2..=MAX_CUSTOM_LENGTH).contains(&lower.len())
+            || !lower.iter().all_unique()
+            || lower.iter().any(|c| c.is_whitespace())
+        {
+            return Err(OperationError::invalid_parameter(
+                "params.lang.lower",
+                format!("Alphabet must consist of 2 to {MAX_CUSTOM_LENGTH} unique non-whitespace characters."),
+                format!("Алфавит должен состоять из 2-{MAX_CUSTOM_LENGTH} неповторяющихся непробельных символов."),
+            ));
+        }
+
+        let upper = match &self.upper {
+            Some(upper) => upper.chars().collect_vec(),
+            None => lower
+                .iter()
+                .map(|&c| c.to_uppercase().exactly_one().unwrap_or(c))
+                .collect(),
+        };
+        let clashes = upper.iter().enumerate().any(|(idx, c)| {
+            lower
+                .iter()
+                .position(|x| x == c)
+                .is_some_and(|pos| pos != idx)
+        });
+        if upper.len() != lower.len() || !upper.iter().all_unique() || clashes {
+            return Err(OperationError::invalid_parameter(
+                "params.lang.upper",
+                "Uppercase letters must be unique and match the lowercase ones one to one.",
+                "Заглавные буквы должны быть уникальны и соответствовать строчным один к одному.",
+            ));
+        }
+
+        Ok(Alphabet::new(lower, upper, None))
+    }
+}
+
+/// Letters of a language in both cases together with its Polybius square.
+#[derive(Debug, Clone)]
+pub struct Alphabet {
+    lower: Vec<char>,
+    upper: Vec<char>,
+    /// Uppercase letter written as another one in the Polybius square, `J` as `I` in english.
+    merge: Option<(char, char)>,
+    polybius: Vec<char>,
+    square_size: usize,
+}
+
+impl From<SupportedLanguages> for Alphabet {
+    fn from(lang: SupportedLanguages) -> Self {
+        let (lower, upper, merge) = match lang {
+            SupportedLanguages::EN => (
+                "abcdefghijklmnopqrstuvwxyz",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+                Some(('J', 'I')),
+            ),
+            SupportedLanguages::RU => (
+                "абвгдежзийклмнопрстуфхцчшщъыьэюя",
+                "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+                None,
+            ),
+            SupportedLanguages::RU_WITH_YO => (
+                "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
+                "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+                None,
+            ),
+            SupportedLanguages::DE => (
+                "abcdefghijklmnopqrstuvwxyzäöüß",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜẞ",
+                None,
+            ),
+            SupportedLanguages::UK => (
+                "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя",
+                "АБВГҐДЕЄЖЗИІЇЙКЛМНОПРСТУФХЦЧШЩЬЮЯ",
+                None,
+            ),
+            SupportedLanguages::EL => {
+                ("αβγδεζηθικλμνξοπρστυφχψω", "ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ", None)
+            }
+            SupportedLanguages::LATIN_DIGITS => (
+                "abcdefghijklmnopqrstuvwxyz0123456789",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789",
+                None,
+            ),
+        };
+
+        Self::new(lower.chars().collect(), upper.chars().collect(), merge)
+    }
+}
+
+impl Alphabet {
+    fn new(lower: Vec<char>, upper: Vec<char>, merge: Option<(char, char)>) -> Self {
+        let letters = upper
+            .iter()
+            .copied()
+            .filter(|&c| merge.is_none_or(|(from, _)| c != from))
+            .collect_vec();
+        let square_size = (letters.len() as f64).sqrt().ceil() as usize;
+        let padding = POLYBIUS_PADDING
+            .chars()
+            .filter(|c| !lower.contains(c) && !upper.contains(c));
+        let polybius = letters
+            .into_iter()
+            .chain(padding)
+            .take(square_size * square_size)
+            .collect();
+
+        Self {
+            lower,
+            upper,
+            merge,
+            polybius,
+            square_size,
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.lower.len()
+    }
+
+    pub fn lower(&self) -> &[char] {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &[char] {
+        &self.upper
+    }
+
+    /// Position of the letter in either case.
+    pub fn index_of(&self, c: char) -> Option<usize> {
+        self.lower
+            .iter()
+            .position(|&x| x == c)
+            .or_else(|| self.upper.iter().position(|&x| x == c))
+    }
+
+    pub fn char_at(&self, idx: usize, upper: bool) -> char {
+        match upper {
+            true => self.upper[idx],
+            false => self.lower[idx],
+        }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.index_of(c).is_some()
+    }
+
+    /// Whether the text is not empty and only has letters of the alphabet,
+    /// digits, punctuation and whitespace.
+    pub fn validate(&self, text: &str) -> bool {
+        let mut buf = [0; 4];
+
+        !text.is_empty()
+            && text
+                .chars()
+                .all(|c| self.contains(c) || OTHER.is_match(c.encode_utf8(&mut buf)))
+    }
+
+    /// Side of the Polybius square.
+    pub fn square_size(&self) -> usize {
+        self.square_size
+    }
+
+    /// Uppercase letters row by row, padded with punctuation and digits to fill the square.
+    pub fn polybius(&self) -> Result<&[char], OperationError> {
+        if self.polybius.len() != self.square_size * self.square_size {
+            return Err(OperationError::invalid_parameter(
+                "params.lang",
+                "Alphabet doesn't fit into a Polybius square.",
+                "Алфавит не помещается в квадрат Полибия.",
+            ));
+        }
+        Ok(&self.polybius)
+    }
+
+    /// The character as it is written in the Polybius square.
+    pub fn to_square(&self, c: char) -> char {
+        let c = match self.index_of(c) {
+            Some(idx) => self.upper[idx],
+            None => c,
+        };
+        match self.merge {
+            Some((from, to)) if c == from => to,
+            _ => c,
+        }
+    }
+}
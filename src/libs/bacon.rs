@@ -1,8 +1,9 @@
 use std::collections::BTreeMap;
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::utils::{get_alphabet, SupportedLanguages};
+use crate::{libs::alphabet::Alphabet, utils::SupportedLanguages};
 
 pub struct BaconCipher {
     en_map: BTreeMap<char, String>,
@@ -15,31 +16,21 @@ impl BaconCipher {
         item_b: char,
         translation: SupportedBaconTranslation,
         alp: SupportedBaconAlphabet,
-        lang: SupportedLanguages,
+        alphabet: &Alphabet,
     ) -> Self {
-        let (alphabet, _, _, _, length, _) = get_alphabet(&lang);
+        let codes = alphabet
+            .lower()
+            .iter()
+            .map(|&c| Self::char_by_alphabet(c, alphabet, &translation, &alp, &item_a, &item_b))
+            .collect::<Vec<_>>();
 
-        let en_map = alphabet
-            .chars()
-            .map(|c| {
-                Self::char_by_alphabet(c, alphabet, &translation, &alp, &length, &item_a, &item_b)
-            })
-            .collect();
+        let en_map = codes.iter().cloned().collect();
 
-        let de_map = alphabet
-            .chars()
-            .map(|c| {
-                let x = Self::char_by_alphabet(
-                    c,
-                    alphabet,
-                    &translation,
-                    &alp,
-                    &length,
-                    &item_a,
-                    &item_b,
-                );
-                (x.1, x.0.to_string())
-            })
+        // Letters sharing a code in the standard alphabet decode to the first of them.
+        let de_map = codes
+            .into_iter()
+            .rev()
+            .map(|(c, code)| (code, c.to_string()))
             .collect();
 
         BaconCipher { en_map, de_map }
@@ -68,41 +59,36 @@ impl BaconCipher {
 impl BaconCipher {
     fn char_by_alphabet(
         c: char,
-        alphabet: &str,
+        alphabet: &Alphabet,
         translation: &SupportedBaconTranslation,
         alp: &SupportedBaconAlphabet,
-        length: &u8,
         a: &char,
         b: &char,
     ) -> (char, String) {
-        let костыль = match alp {
+        let idx = alphabet.index_of(c).unwrap();
+        // The standard alphabet writes J as I and V as U.
+        let idx = match alp {
             SupportedBaconAlphabet::Standard => {
-                if ('a'..'j').contains(&c) {
-                    0
-                } else if ('j'..'v').contains(&c) {
-                    1
-                } else {
-                    2
-                }
+                idx - alphabet.lower()[..=idx]
+                    .iter()
+                    .filter(|x| ['j', 'v'].contains(x))
+                    .count()
             }
-            SupportedBaconAlphabet::Complete => 0,
+            SupportedBaconAlphabet::Complete => idx,
         };
+        let width = (usize::BITS - (alphabet.length() - 1).leading_zeros()) as usize;
 
-        let string = match (*length as f64).sqrt().round() as i8 {
-            5 => format!("{:05b}", alphabet.find(c).unwrap() - костыль),
-            6 => format!("{:06b}", alphabet.find(c).unwrap()),
-            _ => unreachable!(),
-        }
-        .chars()
-        .map(|x| match translation {
-            SupportedBaconTranslation::ZeroOne => x,
-            SupportedBaconTranslation::AB => match x {
-                '0' => *a,
-                '1' => *b,
-                _ => unreachable!(),
-            },
-        })
-        .collect();
+        let string = format!("{:0width$b}", idx)
+            .chars()
+            .map(|x| match translation {
+                SupportedBaconTranslation::ZeroOne => x,
+                SupportedBaconTranslation::AB => match x {
+                    '0' => *a,
+                    '1' => *b,
+                    _ => unreachable!(),
+                },
+            })
+            .collect();
 
         (c, string)
     }
@@ -115,12 +101,12 @@ impl Default for BaconCipher {
             'B',
             SupportedBaconTranslation::AB,
             SupportedBaconAlphabet::Standard,
-            SupportedLanguages::EN,
+            &Alphabet::from(SupportedLanguages::EN),
         )
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub enum SupportedBaconTranslation {
     #[serde(rename = "0/1")]
     ZeroOne,
@@ -128,7 +114,7 @@ pub enum SupportedBaconTranslation {
     AB,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub enum SupportedBaconAlphabet {
     #[serde(alias = "Standard (I=J and V=U)")]
     Standard,
@@ -4,10 +4,11 @@ use crate::{
         expand_alphabet_range, get_char_by_index, str_to_array_buffer_by_alphabet,
         DataRepresentation, DataRepresentationInput,
     },
+    OperationError,
 };
 use itertools::Itertools;
 
-pub fn to_base64(data: &[u8], alphabet: Option<String>) -> Result<String, String> {
+pub fn to_base64(data: &[u8], alphabet: Option<String>) -> Result<String, OperationError> {
     if data.is_empty() {
         return Ok(String::new());
     }
@@ -19,11 +20,15 @@ pub fn to_base64(data: &[u8], alphabet: Option<String>) -> Result<String, String
     let alphabet_length = alphabet.chars().count();
 
     if alphabet_length != 64 && alphabet_length != 65 {
-        return Err(format!("Invalid base64 alphabet length. ({alphabet_length}):\n{alphabet}"));
+        return Err(OperationError::invalid_parameter(
+            "params.alphabet",
+            format!("Invalid base64 alphabet length. ({alphabet_length}):\n{alphabet}"),
+            format!("Неверная длина алфавита base64. ({alphabet_length}):\n{alphabet}"),
+        ));
     }
 
     let mut output = String::new();
-    let mut padding = 0;
+    let padding = (3 - data.len() % 3) % 3;
 
     data.iter()
         .fold(String::new(), |acc, x| acc + &format!("{:08b}", x))
@@ -32,14 +37,7 @@ pub fn to_base64(data: &[u8], alphabet: Option<String>) -> Result<String, String
         .into_iter()
         .map(|x| {
             let sextet = x.collect::<String>();
-            match sextet.len() {
-                6 => u8::from_str_radix(&sextet, 2),
-                _ => {
-                    padding += 1;
-                    u8::from_str_radix(&format!("{:0<6}", sextet), 2)
-                }
-            }
-            .unwrap()
+            u8::from_str_radix(&format!("{:0<6}", sextet), 2).unwrap()
         })
         .for_each(|x| output.push(get_char_by_index(&alphabet, x)));
 
@@ -56,12 +54,9 @@ pub fn from_base64(
     return_type: DataRepresentationInput,
     remove_non_alphabetic_chars: bool,
     strict_mode: bool,
-) -> Result<DataRepresentation, String> {
+) -> Result<DataRepresentation, OperationError> {
     if data.is_empty() {
-        return match return_type {
-            DataRepresentationInput::String => Ok(DataRepresentation::String(String::new())),
-            DataRepresentationInput::ByteArray => Ok(DataRepresentation::ByteArray(Vec::new())),
-        };
+        return DataRepresentation::ByteArray(Vec::new()).convert(return_type);
     }
 
     if alphabet.is_empty() {
@@ -71,7 +66,10 @@ pub fn from_base64(
     if !remove_non_alphabetic_chars {
         let regex = regex::Regex::new(&format!("[^{}]", alphabet)).unwrap();
         if regex.is_match(&data) {
-            return Err("Input string isn't correspond to used base64 alphabet.".to_string());
+            return Err(OperationError::invalid_input_encoding(
+                "Input string isn't correspond to used base64 alphabet.",
+                "Входная строка не соответствует используемому алфавиту base64.",
+            ));
         }
     }
 
@@ -79,7 +77,11 @@ pub fn from_base64(
     let alphabet_length = alphabet.chars().count();
 
     if alphabet_length != 64 && alphabet_length != 65 {
-        return Err("Invalid base64 alphabet length.".to_string());
+        return Err(OperationError::invalid_parameter(
+            "params.alphabet",
+            "Invalid base64 alphabet length.",
+            "Неверная длина алфавита base64.",
+        ));
     }
 
     if remove_non_alphabetic_chars {
@@ -88,9 +90,15 @@ pub fn from_base64(
 
     if strict_mode {
         if data.len() % 4 == 1 {
-            return Err(format!(
-                "Invalid Base64 input length ({}) cannot be 4n+1, even without padding chars.",
-                data.len()
+            return Err(OperationError::invalid_input_encoding(
+                format!(
+                    "Invalid Base64 input length ({}) cannot be 4n+1, even without padding chars.",
+                    data.len()
+                ),
+                format!(
+                    "Длина входных данных Base64 ({}) не может быть 4n+1, даже без символов дополнения.",
+                    data.len()
+                ),
             ));
         }
 
@@ -100,14 +108,17 @@ pub fn from_base64(
 
             if let Some(pad_pos) = pad_pos {
                 if pad_pos < data.len() - 2 || get_char_by_index(&data, data.len() - 1) != pad {
-                    return Err(
-                        "Base64 padding character ({pad}) not used in the correct place."
-                            .to_string(),
-                    );
+                    return Err(OperationError::invalid_input_encoding(
+                        format!("Base64 padding character ({pad}) not used in the correct place."),
+                        format!("Символ дополнения Base64 ({pad}) стоит не на своём месте."),
+                    ));
                 }
 
-                if data.len() % 4 != 0 {
-                    return Err("Base64 not padded to a multiple of 4.".to_string());
+                if !data.len().is_multiple_of(4) {
+                    return Err(OperationError::invalid_input_encoding(
+                        "Base64 not padded to a multiple of 4.",
+                        "Длина Base64 с дополнением не кратна 4.",
+                    ));
                 }
             }
         }
@@ -119,7 +130,7 @@ pub fn from_base64(
             .to_string();
     }
 
-    return match return_type {
+    match return_type {
         DataRepresentationInput::String => {
             let mut output = String::new();
             str_to_array_buffer_by_alphabet(&data, &alphabet)
@@ -138,7 +149,7 @@ pub fn from_base64(
 
             Ok(DataRepresentation::String(output))
         }
-        DataRepresentationInput::ByteArray => {
+        return_type => {
             let mut output = Vec::new();
 
             str_to_array_buffer_by_alphabet(&data, &alphabet)
@@ -148,14 +159,13 @@ pub fn from_base64(
                 .chars()
                 .chunks(8)
                 .into_iter()
-                .map(|x| u8::from_str_radix(&x.collect::<String>(), 2).unwrap())
-                .for_each(|x| output.push(x));
+                .map(|x| x.collect::<String>())
+                .filter(|x| x.len() == 8)
+                .for_each(|x| output.push(u8::from_str_radix(&x, 2).unwrap()));
 
-            Ok(DataRepresentation::ByteArray(
-                output[..output.len() - 1].to_vec(),
-            ))
+            DataRepresentation::ByteArray(output).convert(return_type)
         }
-    };
+    }
 }
 
 #[allow(dead_code)]
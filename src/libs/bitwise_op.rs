@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub fn bit_op<F>(input: &[u8], key: &[u8], func: F) -> Vec<u8>
@@ -40,7 +41,7 @@ pub fn sub(operand: u8, key: u8) -> u8 {
     }
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, JsonSchema, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BitwiseOpDelimiters {
     Hex,
@@ -1,71 +1,300 @@
 use crate::{
-    traits::CharTrait,
-    utils::{
-        get_alphabet, get_char_by_index, get_index_by_char, modulus, validate_lang,
-        SupportedLanguages,
-    },
+    libs::alphabet::Alphabet,
+    utils::{mod_inv, modulus},
+    OperationError,
 };
 use itertools::Itertools;
 use num::Integer;
 
 pub fn affine_cipher_encode(
     input: &str,
-    lang: SupportedLanguages,
+    alphabet: &Alphabet,
     a: i16,
     b: i16,
-) -> Result<String, String> {
-    if !validate_lang(input, &lang) {
-        return Err("Wrong language.".to_string());
+) -> Result<String, OperationError> {
+    if !alphabet.validate(input) {
+        return Err(OperationError::invalid_input(
+            "Wrong language.",
+            "Неверный язык.",
+        ));
     };
 
-    let (alp_lower, alp_upper, _, _, alp_length, _) = get_alphabet(&lang);
-    if a.gcd(&(alp_length as i16)) != 1 {
-        return Err(format!(
-            "The value of `a` must be coprime to alphabet length({}).",
-            alp_length
+    let alp_length = alphabet.length() as i16;
+    if a.gcd(&alp_length) != 1 {
+        return Err(OperationError::invalid_parameter(
+            "params.a",
+            format!("The value of `a` must be coprime to alphabet length({alp_length})."),
+            format!("Значение `a` должно быть взаимно простым с длиной алфавита({alp_length})."),
         ));
     }
 
-    let mut output = String::with_capacity(alp_length as usize);
+    let mut output = String::with_capacity(input.len());
 
     for c in input.chars() {
-        if !c.is_alphabetic() {
+        let Some(x) = alphabet.index_of(c) else {
             output.push(c);
             continue;
-        }
+        };
+
+        let x = modulus(a * x as i16 + b, alp_length);
+        output.push(alphabet.char_at(x as usize, c.is_uppercase()));
+    }
 
-        let x = match c.is_lowercase() {
-            true => get_index_by_char(alp_lower, c),
-            false => get_index_by_char(alp_upper, c),
-        } as i16;
+    Ok(output)
+}
 
-        let x = modulus(a * x + b, alp_length as i16);
+pub fn affine_cipher_decode(
+    input: &str,
+    alphabet: &Alphabet,
+    a: i16,
+    b: i16,
+) -> Result<String, OperationError> {
+    if !alphabet.validate(input) {
+        return Err(OperationError::invalid_input(
+            "Wrong language.",
+            "Неверный язык.",
+        ));
+    };
 
-        output.push(match c.is_lowercase() {
-            true => get_char_by_index(alp_lower, x),
-            false => get_char_by_index(alp_upper, x).to_upper_case(),
-        });
+    let alp_length = alphabet.length() as i16;
+    if a.gcd(&alp_length) != 1 {
+        return Err(OperationError::invalid_parameter(
+            "params.a",
+            format!("The value of `a` must be coprime to alphabet length({alp_length})."),
+            format!("Значение `a` должно быть взаимно простым с длиной алфавита({alp_length})."),
+        ));
+    }
+
+    let inv_a = mod_inv(a, alp_length);
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        let Some(y) = alphabet.index_of(c) else {
+            output.push(c);
+            continue;
+        };
+
+        let x = modulus(inv_a * (y as i16 - b), alp_length);
+        output.push(alphabet.char_at(x as usize, c.is_uppercase()));
     }
 
     Ok(output)
 }
 
-pub fn gen_polybius_square(alphabet: &SupportedLanguages, keyword: &str) -> Vec<char> {
-    let (_, _, _, alpha, _, _) = get_alphabet(alphabet);
-    let pol_array = format!("{}{}", keyword, alpha)
+/// Polybius square of the alphabet starting with the unique letters of the keyword.
+pub fn gen_polybius_square(
+    alphabet: &Alphabet,
+    keyword: &str,
+) -> Result<Vec<char>, OperationError> {
+    let square = alphabet.polybius()?;
+    let keyword = keyword
         .chars()
+        .map(|c| alphabet.to_square(c))
+        .collect::<Vec<_>>();
+
+    if keyword.is_empty() || !keyword.iter().all(|c| square.contains(c)) {
+        return Err(OperationError::invalid_parameter(
+            "params.keyword",
+            "The key must consist only of your alphabets characters.",
+            "Ключ должен состоять только из символов выбранного алфавита.",
+        ));
+    }
+
+    Ok(keyword
+        .into_iter()
+        .chain(square.iter().copied())
         .unique()
+        .take(square.len())
+        .collect())
+}
+
+pub fn bifid_cipher_encode(
+    input: &str,
+    alphabet: &Alphabet,
+    keyword: &str,
+) -> Result<String, OperationError> {
+    let polybius = gen_polybius_square(alphabet, keyword)?;
+    let size = alphabet.square_size();
+
+    let (mut rows, columns): (Vec<_>, Vec<_>) = bifid_positions(input, alphabet, &polybius)
+        .map(|idx| (idx / size, idx % size))
+        .unzip();
+    rows.extend(columns);
+
+    let letters = rows
+        .chunks(2)
+        .map(|pair| polybius[pair[0] * size + pair[1]]);
+
+    Ok(bifid_output(input, alphabet, &polybius, letters))
+}
+
+pub fn bifid_cipher_decode(
+    input: &str,
+    alphabet: &Alphabet,
+    keyword: &str,
+) -> Result<String, OperationError> {
+    let polybius = gen_polybius_square(alphabet, keyword)?;
+    let size = alphabet.square_size();
+
+    let coordinates = bifid_positions(input, alphabet, &polybius)
+        .flat_map(|idx| [idx / size, idx % size])
         .collect::<Vec<_>>();
+    let (rows, columns) = coordinates.split_at(coordinates.len() / 2);
+
+    let letters = rows
+        .iter()
+        .zip(columns)
+        .map(|(row, column)| polybius[row * size + column]);
+
+    Ok(bifid_output(input, alphabet, &polybius, letters))
+}
+
+/// Positions in the square of the characters of the input that are in it.
+fn bifid_positions<'a>(
+    input: &'a str,
+    alphabet: &'a Alphabet,
+    polybius: &'a [char],
+) -> impl Iterator<Item = usize> + 'a {
+    input
+        .chars()
+        .filter_map(|c| polybius.iter().position(|&x| x == alphabet.to_square(c)))
+}
+
+/// Puts the letters in place of the characters of the input that are in the square.
+/// Letters are uppercase only in place of uppercase characters, the punctuation
+/// padding the square has no case so the letters in its place come out lowercase.
+fn bifid_output(
+    input: &str,
+    alphabet: &Alphabet,
+    polybius: &[char],
+    mut letters: impl Iterator<Item = char>,
+) -> String {
+    input
+        .chars()
+        .map(|c| match polybius.contains(&alphabet.to_square(c)) {
+            true => {
+                let letter = letters.next().unwrap();
+                match alphabet.index_of(letter) {
+                    Some(idx) => alphabet.char_at(idx, c.is_uppercase()),
+                    None => letter,
+                }
+            }
+            false => c,
+        })
+        .collect()
+}
+
+/// Shifts every letter of the alphabet by `shift`, keeping the case and every other character.
+pub fn caesar_cipher(input: &str, alphabet: &Alphabet, shift: i64) -> String {
+    let length = alphabet.length() as i64;
+
+    input
+        .chars()
+        .map(|c| match alphabet.index_of(c) {
+            Some(idx) => {
+                let idx = modulus(idx as i64 + shift % length, length) as usize;
+                alphabet.char_at(idx, c.is_uppercase())
+            }
+            None => c,
+        })
+        .collect()
+}
 
-    let size = match alphabet {
-        SupportedLanguages::EN => 5,
-        SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => 6,
+pub fn rot13(input: &str, amount: i64, lower: bool, upper: bool, numbers: bool) -> String {
+    let rotate = |c: char, first: char, size: i64| {
+        let idx = modulus(c as i64 - first as i64 + amount % size, size);
+        char::from_u32(first as u32 + idx as u32).unwrap()
     };
-    let mut polybius: Vec<char> = Vec::new();
 
-    for i in 0..size {
-        polybius.extend(pol_array[i * size..i * size + size].iter());
+    input
+        .chars()
+        .map(|c| match c {
+            'a'..='z' if lower => rotate(c, 'a', 26),
+            'A'..='Z' if upper => rotate(c, 'A', 26),
+            '0'..='9' if numbers => rotate(c, '0', 10),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Rotates printable ascii characters from `!` to `~`.
+pub fn rot47(input: &str, amount: i64) -> String {
+    input
+        .chars()
+        .map(|c| match c {
+            '!'..='~' => {
+                let idx = modulus(c as i64 - '!' as i64 + amount % 94, 94);
+                char::from_u32('!' as u32 + idx as u32).unwrap()
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+/// Ranges of the Basic Multilingual Plane rotated by ROT8000, whitespace, control characters
+/// and surrogates are left out.
+const ROT8000_RANGES: [(u32, u32); 9] = [
+    (0x21, 0x7f),
+    (0xa1, 0x1680),
+    (0x1681, 0x2000),
+    (0x200b, 0x2028),
+    (0x202a, 0x202f),
+    (0x2030, 0x205f),
+    (0x2060, 0x3000),
+    (0x3001, 0xd800),
+    (0xe000, 0x10000),
+];
+
+/// Rotates the printable characters of the Basic Multilingual Plane by half their count,
+/// so applying it twice gives back the input.
+pub fn rot8000(input: &str) -> String {
+    let total: u32 = ROT8000_RANGES.iter().map(|(start, end)| end - start).sum();
+
+    input
+        .chars()
+        .map(|c| {
+            let mut offset = 0;
+            let Some(idx) = ROT8000_RANGES.iter().find_map(|&(start, end)| {
+                let found = (start..end)
+                    .contains(&(c as u32))
+                    .then(|| offset + c as u32 - start);
+                offset += end - start;
+                found
+            }) else {
+                return c;
+            };
+
+            let mut idx = (idx + total / 2) % total;
+            for (start, end) in ROT8000_RANGES {
+                if idx < end - start {
+                    return char::from_u32(start + idx).unwrap();
+                }
+                idx -= end - start;
+            }
+            unreachable!()
+        })
+        .collect()
+}
+
+/// Half of the alphabet the Porta cipher swaps letters with, the alphabet has to be of even length.
+pub fn porta_half(alphabet: &Alphabet) -> Result<i16, OperationError> {
+    let length = alphabet.length();
+    if length.is_odd() {
+        return Err(OperationError::invalid_parameter(
+            "params.lang",
+            format!("Porta cipher needs an alphabet of even length, not {length}."),
+            format!("Шифру Порта нужен алфавит чётной длины, а не {length}."),
+        ));
     }
+    Ok(length as i16 / 2)
+}
 
-    polybius
+/// Letter of the other half of the alphabet, moved by the pair the key letter is in.
+/// Every key letter swaps letters pairwise so the cipher is its own inverse.
+pub fn porta(text_idx: i16, key_idx: i16, half: i16) -> i16 {
+    let shift = key_idx / 2;
+    match text_idx < half {
+        true => half + modulus(text_idx + shift, half),
+        false => modulus(text_idx - half - shift, half),
+    }
 }
@@ -0,0 +1,253 @@
+use std::io::Read;
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b, VarBlake2s,
+};
+use digest::DynDigest;
+use hmac::{digest::KeyInit, Hmac, Mac};
+use md2::Md2;
+use md4::Md4;
+use md5::Md5;
+use ripemd::{Ripemd128, Ripemd160, Ripemd256, Ripemd320};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use whirlpool::Whirlpool;
+
+use crate::{policy, OperationError};
+
+/// Size of the chunks [`Hasher::update_reader`] reads at once.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Every hash function behind the hashing operations.
+/// Blake2 carries its digest size in bytes, 1..=64 for Blake2b and 1..=32 for Blake2s.
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    MD2,
+    MD4,
+    MD5,
+    SHA1,
+    SHA224,
+    SHA256,
+    SHA384,
+    SHA512,
+    SHA512_224,
+    SHA512_256,
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+    Ripemd128,
+    Ripemd160,
+    Ripemd256,
+    Ripemd320,
+    WhirlPool,
+    Blake2b(usize),
+    Blake2s(usize),
+}
+
+/// Incremental hash or MAC: create it, feed any number of chunks with [`Hasher::update`]
+/// or [`Hasher::update_reader`] and call [`Hasher::finalize`].
+/// The digest is the same as the one the matching operation returns for the whole input.
+/// ``` rust
+/// let mut hasher = Hasher::new(HashAlgorithm::SHA256)?;
+/// hasher.update_reader(File::open("artifact.bin")?)?;
+/// let digest = hasher.finalize();
+/// ```
+pub struct Hasher {
+    inner: Inner,
+}
+
+enum Inner {
+    Digest(Box<dyn DynDigest + Send>),
+    Mac(Box<dyn MacState>),
+    Blake2b(VarBlake2b),
+    Blake2s(VarBlake2s),
+}
+
+/// Object safe part of [`Mac`], every `Hmac<D>` has a different type.
+trait MacState: Send {
+    fn update(&mut self, data: &[u8]);
+    fn finalize(self: Box<Self>) -> Vec<u8>;
+}
+
+impl<M: Mac + Send> MacState for M {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(self, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        Mac::finalize(*self).into_bytes().to_vec()
+    }
+}
+
+impl Hasher {
+    pub fn new(algorithm: HashAlgorithm) -> Result<Self, OperationError> {
+        let digest: Box<dyn DynDigest + Send> = match algorithm {
+            HashAlgorithm::MD2 => Box::new(Md2::default()),
+            HashAlgorithm::MD4 => Box::new(Md4::default()),
+            HashAlgorithm::MD5 => Box::new(Md5::default()),
+            HashAlgorithm::SHA1 => Box::new(Sha1::default()),
+            HashAlgorithm::SHA224 => Box::new(Sha224::default()),
+            HashAlgorithm::SHA256 => Box::new(Sha256::default()),
+            HashAlgorithm::SHA384 => Box::new(Sha384::default()),
+            HashAlgorithm::SHA512 => Box::new(Sha512::default()),
+            HashAlgorithm::SHA512_224 => Box::new(Sha512_224::default()),
+            HashAlgorithm::SHA512_256 => Box::new(Sha512_256::default()),
+            HashAlgorithm::SHA3_224 => Box::new(Sha3_224::default()),
+            HashAlgorithm::SHA3_256 => Box::new(Sha3_256::default()),
+            HashAlgorithm::SHA3_384 => Box::new(Sha3_384::default()),
+            HashAlgorithm::SHA3_512 => Box::new(Sha3_512::default()),
+            HashAlgorithm::Ripemd128 => Box::new(Ripemd128::default()),
+            HashAlgorithm::Ripemd160 => Box::new(Ripemd160::default()),
+            HashAlgorithm::Ripemd256 => Box::new(Ripemd256::default()),
+            HashAlgorithm::Ripemd320 => Box::new(Ripemd320::default()),
+            HashAlgorithm::WhirlPool => Box::new(Whirlpool::default()),
+            HashAlgorithm::Blake2b(_) | HashAlgorithm::Blake2s(_) => {
+                return Self::keyed(algorithm, &[])
+            }
+        };
+
+        Ok(Self {
+            inner: Inner::Digest(digest),
+        })
+    }
+
+    /// Blake2 in its native keyed mode, an empty key is the same as [`Hasher::new`].
+    pub fn keyed(algorithm: HashAlgorithm, key: &[u8]) -> Result<Self, OperationError> {
+        let inner = match algorithm {
+            HashAlgorithm::Blake2b(size) => {
+                check_blake2(size, key, 64)?;
+                Inner::Blake2b(VarBlake2b::new_keyed(key, size))
+            }
+            HashAlgorithm::Blake2s(size) => {
+                check_blake2(size, key, 32)?;
+                Inner::Blake2s(VarBlake2s::new_keyed(key, size))
+            }
+            _ => {
+                return Err(OperationError::invalid_parameter(
+                    "params.hash_function",
+                    "Only Blake2 supports keyed hashing, use HMAC instead.",
+                    "Хеширование с ключом поддерживает только Blake2, используйте HMAC.",
+                ))
+            }
+        };
+
+        Ok(Self { inner })
+    }
+
+    pub fn hmac(algorithm: HashAlgorithm, key: &[u8]) -> Result<Self, OperationError> {
+        let mac: Box<dyn MacState> = match algorithm {
+            HashAlgorithm::MD2 => new_mac::<Hmac<Md2>>(key)?,
+            HashAlgorithm::MD4 => new_mac::<Hmac<Md4>>(key)?,
+            HashAlgorithm::MD5 => new_mac::<Hmac<Md5>>(key)?,
+            HashAlgorithm::SHA1 => new_mac::<Hmac<Sha1>>(key)?,
+            HashAlgorithm::SHA224 => new_mac::<Hmac<Sha224>>(key)?,
+            HashAlgorithm::SHA256 => new_mac::<Hmac<Sha256>>(key)?,
+            HashAlgorithm::SHA384 => new_mac::<Hmac<Sha384>>(key)?,
+            HashAlgorithm::SHA512 => new_mac::<Hmac<Sha512>>(key)?,
+            HashAlgorithm::SHA512_224 => new_mac::<Hmac<Sha512_224>>(key)?,
+            HashAlgorithm::SHA512_256 => new_mac::<Hmac<Sha512_256>>(key)?,
+            HashAlgorithm::SHA3_224 => new_mac::<Hmac<Sha3_224>>(key)?,
+            HashAlgorithm::SHA3_256 => new_mac::<Hmac<Sha3_256>>(key)?,
+            HashAlgorithm::SHA3_384 => new_mac::<Hmac<Sha3_384>>(key)?,
+            HashAlgorithm::SHA3_512 => new_mac::<Hmac<Sha3_512>>(key)?,
+            HashAlgorithm::Ripemd128 => new_mac::<Hmac<Ripemd128>>(key)?,
+            HashAlgorithm::Ripemd160 => new_mac::<Hmac<Ripemd160>>(key)?,
+            HashAlgorithm::Ripemd256 => new_mac::<Hmac<Ripemd256>>(key)?,
+            HashAlgorithm::Ripemd320 => new_mac::<Hmac<Ripemd320>>(key)?,
+            HashAlgorithm::WhirlPool => new_mac::<Hmac<Whirlpool>>(key)?,
+            HashAlgorithm::Blake2b(_) | HashAlgorithm::Blake2s(_) => {
+                return Err(OperationError::invalid_parameter(
+                    "params.hash_function",
+                    "HMAC is not supported for Blake2, use its keyed mode instead.",
+                    "HMAC не поддерживается для Blake2, используйте хеширование с ключом.",
+                ))
+            }
+        };
+
+        Ok(Self {
+            inner: Inner::Mac(mac),
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            Inner::Digest(digest) => digest.update(data),
+            Inner::Mac(mac) => mac.update(data),
+            Inner::Blake2b(hasher) => hasher.update(data),
+            Inner::Blake2s(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Feeds everything left in the reader, holding one chunk in memory at a time.
+    /// Returns the number of bytes read, the deadline and cancellation are checked before every chunk.
+    pub fn update_reader<R: Read>(&mut self, mut reader: R) -> Result<u64, OperationError> {
+        let mut buffer = vec![0; CHUNK_SIZE];
+        let mut total = 0;
+
+        loop {
+            policy::checkpoint()?;
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => return Ok(total),
+                Ok(read) => read,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    return Err(OperationError::invalid_input(
+                        format!("Can't read input: {err}."),
+                        format!("Не удалось прочитать входные данные: {err}."),
+                    ))
+                }
+            };
+            self.update(&buffer[..read]);
+            total += read as u64;
+        }
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        match self.inner {
+            Inner::Digest(digest) => digest.finalize().to_vec(),
+            Inner::Mac(mac) => mac.finalize(),
+            Inner::Blake2b(hasher) => hasher.finalize_boxed().to_vec(),
+            Inner::Blake2s(hasher) => hasher.finalize_boxed().to_vec(),
+        }
+    }
+}
+
+/// Hashes everything in the reader in bounded memory.
+pub fn hash_reader<R: Read>(
+    algorithm: HashAlgorithm,
+    reader: R,
+) -> Result<Vec<u8>, OperationError> {
+    let mut hasher = Hasher::new(algorithm)?;
+    hasher.update_reader(reader)?;
+    Ok(hasher.finalize())
+}
+
+fn new_mac<M>(key: &[u8]) -> Result<Box<dyn MacState>, OperationError>
+where
+    M: Mac + KeyInit + Send + 'static,
+{
+    let mac = <M as Mac>::new_from_slice(key).map_err(OperationError::crypto_failure)?;
+    Ok(Box::new(mac))
+}
+
+fn check_blake2(size: usize, key: &[u8], max: usize) -> Result<(), OperationError> {
+    if !(1..=max).contains(&size) {
+        return Err(OperationError::invalid_parameter(
+            "params.size",
+            format!("Digest size must be from 1 to {max} bytes."),
+            format!("Размер хеша должен быть от 1 до {max} байт."),
+        ));
+    }
+    if key.len() > max {
+        return Err(OperationError::invalid_parameter(
+            "params.key",
+            format!("Key must be at most {max} bytes long."),
+            format!("Длина ключа должна быть не больше {max} байт."),
+        ));
+    }
+    Ok(())
+}
@@ -1,6 +1,9 @@
 pub mod vigenere_trait;
 
+pub mod alphabet;
 pub mod bacon;
 pub mod base64;
 pub mod bitwise_op;
 pub mod ciphers;
+pub mod hashing;
+pub mod scoring;
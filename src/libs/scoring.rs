@@ -0,0 +1,254 @@
+use serde::Serialize;
+
+use crate::{libs::alphabet::Language, utils::SupportedLanguages, OperationError};
+
+/// Relative frequencies (percent) of `a..=z` in english texts.
+pub const EN_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+/// Relative frequencies (percent) of `а..=я` in russian texts, `ё` is counted as `е`.
+pub const RU_FREQUENCIES: [f64; 32] = [
+    8.01, 1.59, 4.54, 1.70, 2.98, 8.45, 0.94, 1.65, 7.35, 1.21, 3.49, 4.40, 3.21, 6.70, 10.97,
+    2.81, 4.73, 5.47, 6.26, 2.62, 0.26, 0.97, 0.48, 1.44, 0.73, 0.36, 0.04, 1.90, 1.74, 0.32, 0.64,
+    2.01,
+];
+
+/// Share of bytes that are printable ascii, whitespace or part of valid non ascii UTF-8 text.
+pub fn printable_ratio(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    let printable = match std::str::from_utf8(data) {
+        Ok(text) => text
+            .chars()
+            .filter(|c| !c.is_control() || c.is_whitespace())
+            .map(char::len_utf8)
+            .sum(),
+        Err(_) => data
+            .iter()
+            .filter(|&&byte| byte.is_ascii_graphic() || byte.is_ascii_whitespace())
+            .count(),
+    };
+
+    printable as f64 / data.len() as f64
+}
+
+/// Shannon entropy in bits per byte, from 0 to 8.
+pub fn entropy(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    let mut counts = [0usize; 256];
+    for &byte in data {
+        counts[byte as usize] += 1;
+    }
+
+    let len = data.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Index of the letter in the frequency table of the language.
+fn letter_index(c: char, lang: SupportedLanguages) -> Option<usize> {
+    let c = c.to_lowercase().next()?;
+    match lang {
+        SupportedLanguages::EN | SupportedLanguages::LATIN_DIGITS => {
+            c.is_ascii_lowercase().then(|| c as usize - 'a' as usize)
+        }
+        SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => match c {
+            'а'..='я' => Some(c as usize - 'а' as usize),
+            'ё' => Some(5),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn frequencies(lang: SupportedLanguages) -> &'static [f64] {
+    match lang {
+        SupportedLanguages::EN | SupportedLanguages::LATIN_DIGITS => &EN_FREQUENCIES,
+        SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => &RU_FREQUENCIES,
+        _ => &[],
+    }
+}
+
+/// Chi-squared distance between letter counts of the text and the language,
+/// `None` when the text has no letters of that language.
+pub fn chi_squared(text: &str, lang: SupportedLanguages) -> Option<f64> {
+    let expected = frequencies(lang);
+    let mut counts = vec![0usize; expected.len()];
+
+    for c in text.chars() {
+        if let Some(idx) = letter_index(c, lang) {
+            counts[idx] += 1;
+        }
+    }
+
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+
+    Some(
+        counts
+            .iter()
+            .zip(expected)
+            .map(|(&count, &frequency)| {
+                let expected = frequency / 100.0 * total as f64;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum(),
+    )
+}
+
+/// Likelihood from 0 to 1 that the text is written in the language.
+/// Mixes the share of letters of the language with how close their frequencies are.
+pub fn language_score(text: &str, lang: SupportedLanguages) -> f64 {
+    let Some(chi) = chi_squared(text, lang) else {
+        return 0.0;
+    };
+
+    let (letters, total) = text.chars().fold((0, 0), |(letters, total), c| {
+        match (letter_index(c, lang), c.is_whitespace()) {
+            (Some(_), _) => (letters + 1, total + 1),
+            (None, true) => (letters, total),
+            (None, false) => (letters, total + 1),
+        }
+    });
+
+    let letter_ratio = letters as f64 / total as f64;
+    letter_ratio / (1.0 + chi / letters as f64)
+}
+
+/// The language the text most likely is in together with its score.
+pub fn detect_language(text: &str) -> Option<(SupportedLanguages, f64)> {
+    [SupportedLanguages::EN, SupportedLanguages::RU]
+        .into_iter()
+        .map(|lang| (lang, language_score(text, lang)))
+        .filter(|(_, score)| *score > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Chance that two letters picked from the text are the same, times the alphabet length
+/// so that random text is about 1 whatever the alphabet, `None` for less than two letters.
+pub fn index_of_coincidence(letters: &[usize], alphabet_length: usize) -> Option<f64> {
+    let total = letters.len();
+    if total < 2 {
+        return None;
+    }
+
+    let mut counts = vec![0usize; alphabet_length];
+    for &idx in letters {
+        counts[idx] += 1;
+    }
+
+    let coincidences: usize = counts
+        .iter()
+        .map(|&count| count * count.saturating_sub(1))
+        .sum();
+    Some(coincidences as f64 / (total * (total - 1)) as f64 * alphabet_length as f64)
+}
+
+/// Plaintext a brute force got with the key, flattened into the same object.
+#[derive(Serialize)]
+pub struct Candidate<K> {
+    #[serde(flatten)]
+    pub key: K,
+    pub output: String,
+    pub chi_squared: f64,
+}
+
+/// The language candidates of a brute force over this alphabet are scored in.
+pub fn scored_language(lang: &Language) -> Result<SupportedLanguages, OperationError> {
+    match lang {
+        Language::Builtin(lang) if !frequencies(*lang).is_empty() => Ok(*lang),
+        _ => Err(OperationError::invalid_parameter(
+            "params.lang",
+            "Only english and russian texts can be scored.",
+            "Оценивать можно только английские и русские тексты.",
+        )),
+    }
+}
+
+/// Candidates with their distance to the letter frequencies of the language, in the same order.
+/// Candidates without letters of the language are left out.
+pub fn score_candidates<K>(
+    candidates: impl IntoIterator<Item = (K, String)>,
+    lang: SupportedLanguages,
+) -> Result<Vec<Candidate<K>>, OperationError> {
+    let scored = candidates
+        .into_iter()
+        .filter_map(|(key, output)| {
+            let chi_squared = chi_squared(&output, lang)?;
+            Some(Candidate {
+                key,
+                output,
+                chi_squared,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if scored.is_empty() {
+        return Err(OperationError::invalid_input(
+            "Input has no letters to score.",
+            "Во входных данных нет букв для оценки.",
+        ));
+    }
+
+    Ok(scored)
+}
+
+/// `limit` candidates closest to the letter frequencies of the language, best first.
+/// Candidates with the same score keep their order.
+pub fn rank_candidates<K>(
+    candidates: impl IntoIterator<Item = (K, String)>,
+    lang: SupportedLanguages,
+    limit: usize,
+) -> Result<Vec<Candidate<K>>, OperationError> {
+    let mut ranked = score_candidates(candidates, lang)?;
+    ranked.sort_by(|a, b| a.chi_squared.total_cmp(&b.chi_squared));
+    ranked.truncate(limit);
+
+    Ok(ranked)
+}
+
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "PNG image"),
+    (b"\xff\xd8\xff", "JPEG image"),
+    (b"GIF87a", "GIF image"),
+    (b"GIF89a", "GIF image"),
+    (b"%PDF-", "PDF document"),
+    (b"PK\x03\x04", "ZIP archive"),
+    (b"\x1f\x8b", "GZIP archive"),
+    (b"7z\xbc\xaf\x27\x1c", "7z archive"),
+    (b"Rar!\x1a\x07", "RAR archive"),
+    (b"BZh", "BZIP2 archive"),
+    (b"\xfd7zXZ\x00", "XZ archive"),
+    (b"\x7fELF", "ELF executable"),
+    (b"MZ\x90\x00", "Windows executable"),
+    (b"\xca\xfe\xba\xbe", "Java class"),
+    (b"OggS", "OGG media"),
+    (b"ID3\x03", "MP3 audio"),
+    (b"ID3\x04", "MP3 audio"),
+    (b"fLaC", "FLAC audio"),
+    (b"SQLite format 3\x00", "SQLite database"),
+    (b"\x00asm", "WebAssembly binary"),
+];
+
+/// Name of the file type when the data starts with a known magic number.
+pub fn file_signature(data: &[u8]) -> Option<&'static str> {
+    SIGNATURES
+        .iter()
+        .find(|(signature, _)| data.starts_with(signature))
+        .map(|(_, name)| *name)
+}
@@ -1,70 +1,139 @@
-use regex::Regex;
-use std::collections::HashMap;
+use std::collections::VecDeque;
 
 use crate::{
-    traits::CharTrait,
-    utils::{get_alphabet, get_char_by_index, modulus, validate_lang, SupportedLanguages},
+    libs::alphabet::{Alphabet, Language},
+    utils::modulus,
+    OperationError,
 };
 
 pub trait VigenereCipher {
-    fn cipher<F>(lang: SupportedLanguages, key: &str, input: &str, f: F) -> Result<String, String>
+    fn cipher<F>(lang: &Language, key: &str, input: &str, f: F) -> Result<String, OperationError>
     where
         F: Fn(i16, i16) -> i16,
     {
-        <Self as VigenereCipher>::validate_language(&lang, key, input)?;
+        let alphabet = lang.alphabet()?;
+        <Self as VigenereCipher>::validate_language(&alphabet, key, input)?;
 
-        let (alp, _, _, _, _, reg) = get_alphabet(&lang);
+        let key = key
+            .chars()
+            .filter_map(|c| alphabet.index_of(c))
+            .map(|idx| idx as i16)
+            .collect::<Vec<_>>();
 
-        let map: HashMap<char, usize> =
-            HashMap::from_iter(alp.chars().enumerate().map(|(idx, elem)| (elem, idx)));
+        Ok(repeat_key(&alphabet, &key, input, f))
+    }
 
-        let key = key.to_lowercase();
-        let rg = Regex::new(reg).unwrap();
-        let mut index = 0usize;
-        let mut cipher_text = String::new();
+    /// Same as `cipher` with a key of digits, every digit is the shift of its letter.
+    fn numeric_cipher<F>(
+        lang: &Language,
+        key: &str,
+        input: &str,
+        f: F,
+    ) -> Result<String, OperationError>
+    where
+        F: Fn(i16, i16) -> i16,
+    {
+        let alphabet = lang.alphabet()?;
+        <Self as VigenereCipher>::validate_input(input)?;
 
-        let key_len = key.chars().count();
-        let alp_len = alp.chars().count() as i16;
+        let key = key
+            .chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as i16))
+            .collect::<Option<Vec<_>>>()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| {
+                OperationError::invalid_parameter(
+                    "params.key",
+                    "Key must consist of digits.",
+                    "Ключ должен состоять из цифр.",
+                )
+            })?;
 
-        for c in input.chars() {
-            if !rg.is_match(&c.to_string()) {
-                cipher_text.push(c);
-                continue;
-            }
+        Ok(repeat_key(&alphabet, &key, input, f))
+    }
 
-            let key_idx = map
-                .get(&get_char_by_index(&key, index % key_len))
-                .unwrap()
-                .to_owned() as i16;
+    /// Same as `cipher` with the key followed by the plaintext instead of repeated,
+    /// the plaintext is the input when encrypting and the output when decrypting.
+    fn autokey_cipher<F>(
+        lang: &Language,
+        key: &str,
+        input: &str,
+        f: F,
+        decrypt: bool,
+    ) -> Result<String, OperationError>
+    where
+        F: Fn(i16, i16) -> i16,
+    {
+        let alphabet = lang.alphabet()?;
+        <Self as VigenereCipher>::validate_language(&alphabet, key, input)?;
 
-            let text_idx = match c.is_lowercase() {
-                true => map.get(&c).unwrap(),
-                false => map.get(&c.to_lower_case()).unwrap(),
-            }
-            .to_owned() as i16;
+        let mut key = key
+            .chars()
+            .filter_map(|c| alphabet.index_of(c))
+            .map(|idx| idx as i16)
+            .collect::<VecDeque<_>>();
+        let alp_len = alphabet.length() as i16;
 
-            let idx = f(text_idx, key_idx);
+        Ok(translate(&alphabet, input, |text_idx| {
+            let idx = modulus(f(text_idx, key.pop_front().unwrap()), alp_len);
+            key.push_back(if decrypt { idx } else { text_idx });
+            idx
+        }))
+    }
 
-            let plain_char = get_char_by_index(alp, modulus(idx, alp_len));
-            cipher_text.push(match c.is_lowercase() {
-                true => plain_char,
-                false => plain_char.to_upper_case(),
-            });
+    fn validate_language(
+        alphabet: &Alphabet,
+        key: &str,
+        input: &str,
+    ) -> Result<(), OperationError> {
+        <Self as VigenereCipher>::validate_input(input)?;
 
-            index += 1;
-        }
-        Ok(cipher_text)
+        if key.is_empty() || !key.chars().all(|c| alphabet.contains(c)) {
+            return Err(OperationError::invalid_parameter(
+                "params.key",
+                "Invalid key.",
+                "Неверный ключ.",
+            ));
+        };
+
+        Ok(())
     }
 
-    fn validate_language(lang: &SupportedLanguages, key: &str, input: &str) -> Result<(), String> {
+    fn validate_input(input: &str) -> Result<(), OperationError> {
         if input.is_empty() {
-            return Err("Input is empty".to_string());
-        };
-
-        if !validate_lang(key, lang) {
-            return Err("Invalid key".to_string());
+            return Err(OperationError::invalid_input(
+                "Input is empty.",
+                "Входные данные пусты.",
+            ));
         };
 
         Ok(())
     }
 }
+
+/// Combines every letter of the input with the next letter of the repeated key.
+fn repeat_key<F>(alphabet: &Alphabet, key: &[i16], input: &str, f: F) -> String
+where
+    F: Fn(i16, i16) -> i16,
+{
+    let alp_len = alphabet.length() as i16;
+    let mut index = 0usize;
+
+    translate(alphabet, input, |text_idx| {
+        let idx = f(text_idx, key[index % key.len()]);
+        index += 1;
+        modulus(idx, alp_len)
+    })
+}
+
+/// Replaces every letter of the input by the letter at the index `f` gives for its own index,
+/// keeping its case and every other character.
+fn translate(alphabet: &Alphabet, input: &str, mut f: impl FnMut(i16) -> i16) -> String {
+    input
+        .chars()
+        .map(|c| match alphabet.index_of(c) {
+            Some(idx) => alphabet.char_at(f(idx as i16) as usize, c.is_uppercase()),
+            None => c,
+        })
+        .collect()
+}
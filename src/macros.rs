@@ -18,20 +18,45 @@ macro_rules! regex_check {
 #[macro_export]
 macro_rules! create_me_daddy {
     () => {
-        #[derive(Deserialize)]
+        #[derive(Clone, Deserialize, schemars::JsonSchema)]
         pub struct DeserializeMeDaddy {
-            input: String,
+            input: $crate::utils::DataRepresentation,
+            input_format: Option<$crate::utils::SupportedFormats>,
             params: Params,
         }
+
+        $crate::impl_operation_request!();
+    };
+}
+
+#[macro_export]
+macro_rules! impl_operation_request {
+    () => {
+        impl $crate::OperationRequest for DeserializeMeDaddy {
+            fn set_input(&mut self, input: $crate::utils::DataRepresentation) {
+                self.input = input;
+            }
+
+            fn decode_input(&mut self) -> Result<(), $crate::OperationError> {
+                if let Some(format) = self.input_format.take() {
+                    let input = std::mem::replace(
+                        &mut self.input,
+                        $crate::utils::DataRepresentation::ByteArray(Vec::new()),
+                    );
+                    self.input = $crate::decode_input(input, &format)?;
+                }
+                Ok(())
+            }
+        }
     };
 }
 
 #[macro_export]
 macro_rules! lang_me_daddy {
     () => {
-        #[derive(Deserialize)]
+        #[derive(Clone, Deserialize, schemars::JsonSchema)]
         pub struct DeserializeMeDaddy {
-            input: String,
+            input: $crate::utils::DataRepresentation,
             lang: String,
             params: Params,
         }
@@ -48,18 +73,136 @@ macro_rules! create_info_struct {
             description_en: &'static str,
             description_ru: &'static str,
             info_url: Option<&'static str>,
+            schema: schemars::Schema,
         }
 
-        impl $struct_name {
-            pub fn info() -> String {
-                let structure = Self {
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self {
                     name: $name,
                     documentation: $doc,
                     description_en: $description_en,
                     description_ru: $description_ru,
                     info_url: $info_url,
-                };
-                serde_json::to_string(&structure).unwrap()
+                    schema: Self::schema(),
+                }
+            }
+        }
+
+        impl $struct_name {
+            pub fn info() -> String {
+                serde_json::to_string(&Self::default()).unwrap()
+            }
+
+            /// JSON Schema of the request payload, `input` and `params` included.
+            pub fn schema() -> schemars::Schema {
+                let mut schema = schemars::schema_for!(DeserializeMeDaddy);
+                schema.insert("title".to_string(), $name.into());
+                schema
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! create_operations_registry {
+    ($($variant:ident => $operation:ident, $info:ident, $category:ident;)*) => {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Operations {
+            $($variant,)*
+        }
+
+        impl Operations {
+            pub const ALL: &'static [Operations] = &[$(Operations::$variant,)*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Operations::$variant => stringify!($variant),)*
+                }
+            }
+
+            pub fn category(&self) -> $crate::Category {
+                match self {
+                    $(Operations::$variant => $crate::Category::$category,)*
+                }
+            }
+
+            pub fn info(&self) -> String {
+                match self {
+                    $(Operations::$variant => $info::info(),)*
+                }
+            }
+
+            pub fn info_value(&self) -> serde_json::Value {
+                match self {
+                    $(Operations::$variant => serde_json::to_value($info::default()).unwrap(),)*
+                }
+            }
+
+            /// Runs the operation on a raw json request, the same payload /api/{name} receives.
+            pub fn run_request(
+                &self,
+                request: &str,
+            ) -> Result<serde_json::Value, $crate::OperationError> {
+                match self {
+                    $(Operations::$variant => $crate::run_to_value($operation, request),)*
+                }
+            }
+
+            /// Runs the operation on a value, converting it to the declared input type first.
+            /// The value is used as it is, decode a text input with [`decode_input`]($crate::decode_input).
+            pub fn run(
+                &self,
+                input: $crate::utils::DataRepresentation,
+                params: &serde_json::Value,
+            ) -> Result<$crate::utils::DataRepresentation, $crate::OperationError> {
+                let params = $crate::dish_params(params);
+                match self {
+                    $(Operations::$variant => $crate::run_dish($operation, input, params.as_deref()),)*
+                }
+            }
+
+            /// Checks `params` the way the operation does before it runs, without any input.
+            pub fn validate_params(
+                &self,
+                params: &serde_json::Value,
+            ) -> Result<(), $crate::OperationError> {
+                self.parse_params($crate::dish_params(params).as_deref())
+                    .map(|_| ())
+            }
+
+            /// Deserializes and checks `params` once for any number of [`Operations::run_with_params`].
+            pub(crate) fn parse_params(
+                &self,
+                params: Option<&str>,
+            ) -> Result<$crate::ParsedParams, $crate::OperationError> {
+                match self {
+                    $(Operations::$variant => $crate::parse_params($operation, params),)*
+                }
+            }
+
+            /// Same as [`Operations::run`] with params [`Operations::parse_params`] made for this operation.
+            pub(crate) fn run_with_params(
+                &self,
+                input: $crate::utils::DataRepresentation,
+                params: &$crate::ParsedParams,
+            ) -> Result<$crate::utils::DataRepresentation, $crate::OperationError> {
+                match self {
+                    $(Operations::$variant => $crate::run_dish_with($operation, input, params),)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for Operations {
+            type Err = $crate::OperationError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $(stringify!($variant) => Ok(Operations::$variant),)*
+                    _ => Err($crate::OperationError::UnknownOperation {
+                        name: name.to_string(),
+                    }),
+                }
             }
         }
     };
@@ -1,46 +1,73 @@
-use crate::{create_info_struct, create_me_daddy, utils::char_repr, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, libs::alphabet::Language, Operation, OperationError,
+    DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for A1Z26CipherDecode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, delimiter) = (request.input, format!("{:?}", request.params.delimiter));
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, delimiter, alphabet) = (
+            request.input.into_string()?,
+            request.params.delimiter,
+            request.params.lang.alphabet()?,
+        );
 
-        let delimiter = char_repr(&delimiter);
-        let cipher_text = input.split(delimiter);
+        let cipher_text = input.split(delimiter.as_str());
+        let length = alphabet.length();
 
         let mut plain_text = String::new();
 
         for c in cipher_text {
-            let c = match c.parse::<u8>() {
+            let c = match c.trim().parse::<usize>() {
                 Ok(c) => c,
                 Err(_) => continue,
             };
-            if !(1..=26).contains(&c) {
-                return Err("All numbers must be between 1 and 26.".to_string());
+            if !(1..=length).contains(&c) {
+                return Err(OperationError::invalid_input(
+                    format!("All numbers must be between 1 and {length}."),
+                    format!("Все числа должны быть от 1 до {length}."),
+                ));
             }
-            plain_text.push((c + 96) as char);
+            plain_text.push(alphabet.char_at(c - 1, false));
         }
         Ok(plain_text)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     delimiter: Delimiters,
+    #[serde(default)]
+    lang: Language,
 }
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, JsonSchema, Debug, Clone, Copy)]
 pub enum Delimiters {
     Space,
     Comma,
+    #[serde(alias = "Semi-colon")]
     SemiColon,
     Colon,
+    #[serde(alias = "Line feed")]
     LineFeed,
     CRLF,
 }
 
+impl Delimiters {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::Comma => ",",
+            Self::SemiColon => ";",
+            Self::Colon => ":",
+            Self::LineFeed => "\n",
+            Self::CRLF => "\r\n",
+        }
+    }
+}
+
 create_me_daddy!();
 
 /// A1Z26 is a simple substitution cipher where each letter is replaced by its serial number in the alphabet.
@@ -52,12 +79,14 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "delimiter": string
+///         "delimiter": string,
+///         "lang": Option<Language>
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - delimiter is one of "Space", "Comma", "SemiColon", "Colon", "LineFeed", "CRLF"
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }, "en" by default
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -68,7 +97,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// <br><br/>
@@ -119,10 +148,16 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Invalid delimiter: `Unsupported delimiter`"
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.delimiter",
+///     "message": {
+///       "en": "Invalid value of `params.delimiter`: unknown variant `Unsupported delimiter`, expected one of `Space`, `Comma`, `Semi-colon`, `SemiColon`, `Colon`, `Line feed`, `LineFeed`, `CRLF`.",
+///       "ru": "Недопустимое значение параметра `params.delimiter`: unknown variant `Unsupported delimiter`, expected one of `Space`, `Comma`, `Semi-colon`, `SemiColon`, `Colon`, `Line feed`, `LineFeed`, `CRLF`."
+///     }
+///   }
 /// }
 /// ```
-
 pub struct A1Z26CipherDecode;
 
 const NAME: &str = "A1Z26CipherDecode";
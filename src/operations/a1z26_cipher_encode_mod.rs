@@ -1,34 +1,32 @@
 use crate::{
-    create_info_struct, create_me_daddy, operations::Delimiters, utils::char_repr, Operation,
-    DOCS_URL,
+    create_info_struct, create_me_daddy, libs::alphabet::Language, operations::Delimiters,
+    Operation, OperationError, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for A1Z26CipherEncode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, delimiter) = (request.input, format!("{:?}", request.params.delimiter));
-
-        let mut result = String::new();
-        let delimiter = char_repr(&delimiter);
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, delimiter, alphabet) = (
+            request.input.into_string()?,
+            request.params.delimiter,
+            request.params.lang.alphabet()?,
+        );
 
-        for character in input.chars() {
-            result.push_str(&match character {
-                'a'..='z' => format!("{}{}", character as u8 - 96, delimiter),
-                'A'..='Z' => format!("{}{}", character as u8 - 64, delimiter),
-                _ => "".to_string(),
-            });
-        }
-        if result.is_empty() {
-            return Ok(String::new());
-        }
-        Ok(result[..result.len() - 1].to_string())
+        Ok(input
+            .chars()
+            .filter_map(|c| alphabet.index_of(c))
+            .map(|idx| (idx + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(delimiter.as_str()))
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     delimiter: Delimiters,
+    #[serde(default)]
+    lang: Language,
 }
 
 create_me_daddy!();
@@ -42,12 +40,14 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "delimiter": string
+///         "delimiter": string,
+///         "lang": Option<Language>
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - delimiter is one of "Space", "Comma", "SemiColon", "Colon", "LineFeed", "CRLF"
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }, "en" by default
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -58,7 +58,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// <br><br/>
@@ -81,18 +81,19 @@ create_me_daddy!();
 /// ```
 /// ## №2
 /// ``` http
-/// POST /api/A1Z26CipherDecode
+/// POST /api/A1Z26CipherEncode
 ///
 /// {
-///     "input": "18;9;3;11;18;15;12;12",
+///     "input": "Привет",
 ///     "params": {
-///         "delimiter": "Semi-colon"
+///         "delimiter": "Comma",
+///         "lang": "ru"
 ///     }
 /// }
 /// ```
 /// ```http
 /// {
-///   "Ok": "rickroll"
+///   "Ok": "16,17,9,3,6,19"
 /// }
 /// ```
 /// ## №3
@@ -109,10 +110,16 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Invalid delimiter: `Unsupported delimiter`."
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.delimiter",
+///     "message": {
+///       "en": "Invalid value of `params.delimiter`: unknown variant `Unsupported delimiter`, expected one of `Space`, `Comma`, `Semi-colon`, `SemiColon`, `Colon`, `Line feed`, `LineFeed`, `CRLF`.",
+///       "ru": "Недопустимое значение параметра `params.delimiter`: unknown variant `Unsupported delimiter`, expected one of `Space`, `Comma`, `Semi-colon`, `SemiColon`, `Colon`, `Line feed`, `LineFeed`, `CRLF`."
+///     }
+///   }
 /// }
 /// ```
-
 pub struct A1Z26CipherEncode;
 
 const NAME: &str = "A1Z26CipherEncode";
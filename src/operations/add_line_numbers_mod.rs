@@ -1,30 +1,35 @@
-use crate::{create_info_struct, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, impl_operation_request,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for AddLineNumbers {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let input = request.input;
-        let output = input
-            .split('\n')
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let input = request.input.into_string()?;
+        let lines = input.split('\n').collect::<Vec<_>>();
+        let width = lines.len().to_string().len();
+        let output = lines
+            .iter()
             .enumerate()
-            .fold(String::new(), |acc, (i, x)| {
-                let index = " "
-                    .repeat(input.len() - (i + 1).ilog10() as usize - 1)
-                    .to_string()
-                    + &(i + 1).to_string();
-                acc + &format!("{index} {x}")
-            });
+            .map(|(i, line)| format!("{:>width$} {line}", i + 1))
+            .collect::<Vec<_>>()
+            .join("\n");
 
         Ok(output)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    input: String,
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
 }
 
+impl_operation_request!();
+
 pub struct AddLineNumbers;
 
 const NAME: &str = "Add line numbers";
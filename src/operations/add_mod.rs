@@ -1,29 +1,54 @@
 use crate::{
     create_info_struct, create_me_daddy,
     libs::bitwise_op::{add, bit_op},
-    utils::{convert_to_byte_array, SupportedFormats},
-    Operation, DOCS_URL,
+    utils::{convert_to_byte_array, DataRepresentation, DataRepresentationInput, SupportedFormats},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-impl Operation<'_, DeserializeMeDaddy, String> for ADD {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, Params { key, key_format }) = (request.input, request.params);
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for ADD {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let key = convert_to_byte_array(&key, &key_format)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (
+            input,
+            Params {
+                key,
+                key_format,
+                output_format,
+            },
+        ) = (request.input, request.params);
 
-        let output = String::from_utf8(bit_op(&key, input.as_bytes(), add))
-            .map_err(|err| err.to_string())?;
+        let key = convert_to_byte_array(&key, &key_format).map_err(|err| {
+            OperationError::invalid_parameter(
+                "params.key",
+                format!("Invalid key: {err}."),
+                format!("Неверный ключ: {err}."),
+            )
+        })?;
+        if key.is_empty() {
+            return Err(OperationError::invalid_parameter(
+                "params.key",
+                "Key must not be empty.",
+                "Ключ не может быть пустым.",
+            ));
+        }
 
-        Ok(output)
+        let output = bit_op(&input.into_bytes(), &key, add);
+
+        match output_format {
+            Some(format) => format.encode(output, "params.output_format"),
+            None => Ok(DataRepresentation::from_bytes(output)),
+        }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     key: String,
     key_format: SupportedFormats,
+    output_format: Option<OutputEncoding>,
 }
 
 create_me_daddy!();
@@ -41,12 +66,14 @@ create_me_daddy!();
 ///     "input": string,
 ///     "params": {
 ///         key: String,
-///         key_format: SupportedFormats
+///         key_format: SupportedFormats,
+///         output_format: Option<OutputEncoding>
 ///     }
 /// }
 /// ```
 /// #### where
-///     SupportedFormats is enum of 'binary', 'utf8', 'hex', 'base64', 'latin1'
+///     SupportedFormats is enum of 'binary', 'utf8', 'hex', 'base64', 'latin1', 'decimal'
+///     OutputEncoding encodes the result, see [`OutputEncoding`](crate::OutputEncoding), without it the result is text when it is valid UTF-8 and bytes otherwise
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -57,7 +84,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -102,14 +129,21 @@ create_me_daddy!();
 /// {
 ///     "input": "error",
 ///     "params": {
-///         key: "no formet",
+///         "key": "no formet"
 ///     }
 /// }
 /// ```
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `key_format`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.key_format",
+///     "message": {
+///       "en": "Missing field `params.key_format`.",
+///       "ru": "Отсутствует обязательное поле `params.key_format`."
+///     }
+///   }
 /// }
 /// ```
 pub struct ADD;
@@ -1,10 +1,22 @@
-use crate::{create_info_struct, utils::hex, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, impl_operation_request,
+    utils::{DataRepresentation, DataRepresentationInput, OutputEncoding, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-impl Operation<'_, DeserializeMeDaddy, String> for Adler32CheckSum {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let input: Vec<u8> = request.input.into();
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Adler32CheckSum {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, output_format) = (
+            request.input.into_bytes(),
+            request
+                .params
+                .and_then(|params| params.output_format)
+                .unwrap_or_default(),
+        );
 
         const MOD_ADLER: isize = 65521;
         let (mut a, mut b): (isize, isize) = (1, 0);
@@ -17,15 +29,27 @@ impl Operation<'_, DeserializeMeDaddy, String> for Adler32CheckSum {
         a %= MOD_ADLER;
         b %= MOD_ADLER;
 
-        Ok(hex(b << 16 | a))
+        output_format.encode(
+            ((b << 16 | a) as u32).to_be_bytes().to_vec(),
+            "params.output_format",
+        )
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    output_format: Option<OutputEncoding>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    input: String,
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+    params: Option<Params>,
 }
 
+impl_operation_request!();
+
 pub struct Adler32CheckSum;
 
 const NAME: &str = "Adler32CheckSum";
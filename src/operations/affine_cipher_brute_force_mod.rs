@@ -0,0 +1,185 @@
+use num::Integer;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{
+        alphabet::Language,
+        ciphers::affine_cipher_decode,
+        scoring::{rank_candidates, scored_language, Candidate},
+    },
+    policy, Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, Vec<Candidate<AffineKey>>> for AffineCipherBruteForce {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(
+        &self,
+        request: DeserializeMeDaddy,
+    ) -> Result<Vec<Candidate<AffineKey>>, OperationError> {
+        let (input, lang, limit) = (
+            request.input.into_string()?,
+            scored_language(&request.params.lang)?,
+            request.params.limit.unwrap_or(DEFAULT_LIMIT),
+        );
+        let alphabet = request.params.lang.alphabet()?;
+        let length = alphabet.length();
+        let mut candidates = Vec::new();
+        for a in (1..length).filter(|a| a.gcd(&length) == 1) {
+            for b in 0..length {
+                policy::checkpoint()?;
+                let output = affine_cipher_decode(&input, &alphabet, a as i16, b as i16)?;
+                candidates.push((AffineKey { a, b }, output));
+            }
+        }
+
+        rank_candidates(candidates, lang, limit)
+    }
+}
+
+const DEFAULT_LIMIT: usize = 5;
+
+/// Key to send to AffineCipherDecode.
+#[derive(Serialize)]
+pub struct AffineKey {
+    a: usize,
+    b: usize,
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    lang: Language,
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1), extend("default" = 5))]
+    limit: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Tries every key of the Affine cipher, every `a` coprime to the alphabet length with every `b`, and returns the candidates whose letter frequencies are closest to the language, best first.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Affine_cipher#Cryptanalysis)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AffineCipherBruteForce with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "limit": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "latin_digits", candidates are scored by english or russian letter frequencies
+///     - limit is maximum number of candidates, 5 by default
+///     - a and b of a candidate are the ones to send to AffineCipherDecode, a lower chi_squared means a closer match
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": [ { "a": usize, "b": usize, "output": string, "chi_squared": f64 } ] }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AffineCipherBruteForce
+///
+/// {
+///     "input": "Qccz qc vcip zrc alx zaocp iz ucjcv",
+///     "params": {
+///         "lang": "en",
+///         "limit": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": [
+///     { "a": 5, "b": 8, "output": "Meet me near the old tower at seven", "chi_squared": 18.439811819454746 },
+///     { "a": 25, "b": 2, "output": "Maad ma haun dla crf dcoan ud iatah", "chi_squared": 38.60570699969372 },
+///     { "a": 5, "b": 11, "output": "Btti bt ctpg iwt das idltg pi htktc", "chi_squared": 46.62161612525873 }
+///   ]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AffineCipherBruteForce
+///
+/// {
+///     "input": "Събужбычъь фгсбуг с мгуйж и ъбгуеше чеъбг",
+///     "params": {
+///         "lang": "ru",
+///         "limit": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": [
+///     { "a": 7, "b": 3, "output": "Встретимся завтра в парке у старого моста", "chi_squared": 24.408477910245878 },
+///     { "a": 23, "b": 3, "output": "Вбтрхтимбп чавтра в яаркх г бтароуо мобта", "chi_squared": 55.48439155437965 }
+///   ]
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/AffineCipherBruteForce
+///
+/// {
+///     "input": "Qccz qc vcip",
+///     "params": {
+///         "lang": "en",
+///         "limit": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.limit",
+///     "message": {
+///       "en": "Invalid value of `params.limit`: The number must be `>= 1`.",
+///       "ru": "Недопустимое значение параметра `params.limit`: The number must be `>= 1`."
+///     }
+///   }
+/// }
+/// ```
+pub struct AffineCipherBruteForce;
+
+const NAME: &str = "AffineCipherBruteForce";
+const DESCRIPTION_EN: &str = "Tries every key of the Affine cipher, every `a` coprime to the alphabet length with every `b`, and returns the candidates whose letter frequencies are closest to the language, best first.";
+const DESCRIPTION_RU: &str = "Перебирает все ключи аффинного шифра, каждое `a`, взаимно простое с длиной алфавита, с каждым `b`, и возвращает варианты, частоты букв которых ближе всего к языку, начиная с лучшего.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Affine_cipher#Cryptanalysis");
+
+create_info_struct!(
+    AffineCipherBruteForceInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
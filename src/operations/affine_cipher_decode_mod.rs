@@ -1,63 +1,23 @@
 use crate::{
     create_info_struct, create_me_daddy,
-    traits::{CharTrait, IntegerTrait},
-    utils::{
-        get_alphabet, get_char_by_index, get_index_by_char, mod_inv, modulus, validate_lang,
-        SupportedLanguages,
-    },
-    Operation, DOCS_URL,
+    libs::{alphabet::Language, ciphers::affine_cipher_decode},
+    Operation, OperationError, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for AffineCipherDecode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, params) = (request.input.into_string()?, request.params);
+        let alphabet = params.lang.alphabet()?;
 
-        let (input, params) = (request.input, request.params);
-        if !validate_lang(&input, &params.lang) {
-            return Err("Wrong language.".to_string());
-        };
-
-        let (a, b) = (params.a as i16, params.b as i16);
-
-        let (alp_lower, alp_upper, _, _, alp_length, _) = get_alphabet(&params.lang);
-        if a.gcd(&(alp_length as i16)) != 1 {
-            return Err(format!(
-                "The value of `a` must be coprime to alphabet length({}).",
-                alp_length
-            ));
-        }
-
-        let mut output = String::with_capacity(alp_length as usize);
-
-        for c in input.chars() {
-            if !c.is_alphabetic() {
-                output.push(c);
-                continue;
-            }
-
-            let y = match c.is_lowercase() {
-                true => get_index_by_char(alp_lower, c),
-                false => get_index_by_char(alp_upper, c),
-            } as i16;
-
-            let inv_a = mod_inv(a, alp_length as i16);
-
-            let x = modulus(inv_a * (y - b), alp_length as i16);
-
-            output.push(match c.is_lowercase() {
-                true => get_char_by_index(alp_lower, x),
-                false => get_char_by_index(alp_upper, x).to_upper_case(),
-            });
-        }
-
-        Ok(output)
+        affine_cipher_decode(&input, &alphabet, params.a as i16, params.b as i16)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
-    lang: SupportedLanguages,
+    lang: Language,
     a: u8,
     b: u8,
 }
@@ -75,7 +35,7 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages
+///         "lang": Language
 ///         "a": u8,
 ///         "b": u8,
 ///     }
@@ -83,7 +43,7 @@ create_me_daddy!();
 /// ```
 /// #### where
 ///     - u8 is unsigned 8-bit integer (digit between 0 and 255)
-///     - SupportedLanguages is enum of en, ru, ru_with_yo
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -94,7 +54,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// <br><br/>
@@ -122,7 +82,7 @@ create_me_daddy!();
 /// POST /api/AffineCipherDecode
 ///
 /// {
-///     "input": "Мскньы, Юкс!",
+///     "input": "Оулньэ, Ялу!",
 ///     "params": {
 ///         "a": 5,
 ///         "b" 3,
@@ -150,7 +110,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Invalid value: integer `-5`, expected u8."
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.a",
+///     "message": {
+///       "en": "Invalid value of `params.a`: invalid value: integer `-5`, expected u8.",
+///       "ru": "Недопустимое значение параметра `params.a`: invalid value: integer `-5`, expected u8."
+///     }
+///   }
 /// }
 /// ```
 pub struct AffineCipherDecode;
@@ -1,27 +1,27 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    create_info_struct, create_me_daddy, libs::ciphers::affine_cipher_encode,
-    utils::SupportedLanguages, Operation, DOCS_URL,
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, ciphers::affine_cipher_encode},
+    Operation, OperationError, DOCS_URL,
 };
 impl Operation<'_, DeserializeMeDaddy, String> for AffineCipherEncode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-
-        let (input, lang, a, b) = (
-            request.input,
-            request.params.lang,
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet, a, b) = (
+            request.input.into_string()?,
+            request.params.lang.alphabet()?,
             request.params.a as i16,
             request.params.b as i16,
         );
 
-        affine_cipher_encode(&input, lang, a, b)
+        affine_cipher_encode(&input, &alphabet, a, b)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
-    lang: SupportedLanguages,
+    lang: Language,
     a: u8,
     b: u8,
 }
@@ -39,7 +39,7 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages
+///         "lang": Language
 ///         "a": u8,
 ///         "b": u8,
 ///     }
@@ -47,7 +47,7 @@ create_me_daddy!();
 /// ```
 /// #### where
 ///     - u8 is unsigned 8-bit integer (digit between 0 and 255)
-///     - SupportedLanguages is enum of en, ru, ru_with_yo
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -58,7 +58,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// <br><br/>
@@ -96,7 +96,7 @@ create_me_daddy!();
 /// ```
 /// ```http
 /// {
-///   "Ok": "Мскньы, Юкс!"
+///   "Ok": "Оулньэ, Ялу!"
 /// }
 /// ```
 /// ## №3
@@ -115,7 +115,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Invalid value: integer `-5`, expected u8."
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.a",
+///     "message": {
+///       "en": "Invalid value of `params.a`: invalid value: integer `-5`, expected u8.",
+///       "ru": "Недопустимое значение параметра `params.a`: invalid value: integer `-5`, expected u8."
+///     }
+///   }
 /// }
 /// ```
 pub struct AffineCipherEncode;
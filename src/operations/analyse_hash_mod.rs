@@ -1,11 +1,16 @@
-use crate::{create_info_struct, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, impl_operation_request,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, SerializeMeDaddy> for AnalyseHash {
-    fn do_black_magic(&self, request: &str) -> Result<SerializeMeDaddy, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<SerializeMeDaddy, OperationError> {
         let input = request
             .input
+            .into_string()?
             .chars()
             .map(|x| match x {
                 '\t' | '\n' | ' ' => "".to_string(),
@@ -94,11 +99,14 @@ pub struct SerializeMeDaddy {
     possible_hash_functions: Vec<&'static str>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    input: String,
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
 }
 
+impl_operation_request!();
+
 /// This function tries to determine information about a given hash and suggests which algorithm may have been used to generate it based on its length.
 /// <br><br/>
 /// For more information go [here](https://wikipedia.org/wiki/Argon2)
@@ -130,7 +138,7 @@ pub struct DeserializeMeDaddy {
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
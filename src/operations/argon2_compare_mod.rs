@@ -1,23 +1,54 @@
-use crate::{create_info_struct, create_me_daddy, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, policy, utils::DataRepresentationInput, Operation,
+    OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for Argon2Compare {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let (input, encoded_hash) = (request.input, request.params.encoded_hash);
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, encoded_hash) = (request.input.into_bytes(), request.params.encoded_hash);
+        if let Some(memory) = encoded_cost(&encoded_hash, "m") {
+            policy::check_cost("params.encoded_hash", memory, |policy| {
+                policy.max_memory_cost
+            })?;
+        }
+        if let Some(iterations) = encoded_cost(&encoded_hash, "t") {
+            policy::check_cost("params.encoded_hash", iterations, |policy| {
+                policy.max_time_cost
+            })?;
+        }
+        if let Some(lanes) = encoded_cost(&encoded_hash, "p") {
+            policy::check_cost("params.encoded_hash", lanes, |policy| {
+                policy.max_parallelism
+            })?;
+        }
 
-        let res = argon2::verify_encoded(&encoded_hash, input.as_bytes())
-            .map_err(|err| err.to_string())?;
+        let res = argon2::verify_encoded(&encoded_hash, &input)
+            .map_err(OperationError::crypto_failure)?;
 
         match res {
-            true => Ok(format!("Match `{}`.", input)),
-            false => Err("No match.".to_string()),
+            true => Ok(format!("Match `{}`.", String::from_utf8_lossy(&input))),
+            false => Err(OperationError::invalid_input(
+                "No match.",
+                "Нет совпадения.",
+            )),
         }
     }
 }
 
-#[derive(Deserialize)]
+/// Value of the `m`, `t` or `p` cost of an encoded hash like `$argon2i$v=19$m=4096,t=3,p=1$...`.
+fn encoded_cost(encoded_hash: &str, name: &str) -> Option<u32> {
+    encoded_hash
+        .split('$')
+        .find(|part| part.starts_with("m="))?
+        .split(',')
+        .find_map(|cost| cost.strip_prefix(name)?.strip_prefix('=')?.parse().ok())
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     encoded_hash: String,
 }
@@ -52,7 +83,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -101,7 +132,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `encoded_hash`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.encoded_hash",
+///     "message": {
+///       "en": "Missing field `params.encoded_hash`.",
+///       "ru": "Отсутствует обязательное поле `params.encoded_hash`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Argon2Compare;
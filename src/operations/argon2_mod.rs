@@ -1,17 +1,36 @@
 use crate::{
-    create_info_struct, create_me_daddy,
-    libs::base64::from_base64,
-    utils::{to_hex, DataRepresentation, DataRepresentationInput},
-    Operation, DOCS_URL,
+    create_info_struct, create_me_daddy, policy,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
 use argon2::{Config, ThreadMode, Variant, Version};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_valid::Validate;
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Argon2 {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
 
-impl Operation<'_, DeserializeMeDaddy, String> for Argon2 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-
-        let (params, input) = (request.params, request.input);
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (params, input) = (request.params, request.input.into_bytes());
+        policy::check_cost("params.memory", params.memory, |policy| {
+            policy.max_memory_cost
+        })?;
+        policy::check_cost("params.iterations", params.iterations, |policy| {
+            policy.max_time_cost
+        })?;
+        policy::check_cost("params.parallelism", params.parallelism, |policy| {
+            policy.max_parallelism
+        })?;
         let (salt, variant, mem_cost, time_cost, lanes, hash_length) = (
             params.salt,
             params.argon2_type,
@@ -33,38 +52,22 @@ impl Operation<'_, DeserializeMeDaddy, String> for Argon2 {
             hash_length,
         };
 
-        let hash = argon2::hash_encoded(input.as_bytes(), salt.as_bytes(), &config)
-            .map_err(|err| format!("{}.", err))?;
-
-        let output = match params.output_format {
-            OutputFormat::Encoded => hash,
-            format @ (OutputFormat::Hex | OutputFormat::Raw) => {
-                let raw_hash = hash.split('$').nth(5).unwrap();
-
-                let data = match from_base64(
-                    raw_hash.to_string(),
-                    "",
-                    DataRepresentationInput::String,
-                    false,
-                    false,
-                ) {
-                    Ok(DataRepresentation::String(data)) => data,
-                    _ => unreachable!(),
-                };
-
-                match format {
-                    OutputFormat::Hex => to_hex(data.as_bytes()),
-                    OutputFormat::Raw => data,
-                    _ => unreachable!(),
-                }
+        match params.output_format {
+            Argon2OutputFormat::Encoded(_) => {
+                argon2::hash_encoded(&input, salt.as_bytes(), &config)
+                    .map(DataRepresentation::String)
+                    .map_err(OperationError::crypto_failure)
             }
-        };
-
-        Ok(output)
+            Argon2OutputFormat::Hash(format) => format.encode(
+                argon2::hash_raw(&input, salt.as_bytes(), &config)
+                    .map_err(OperationError::crypto_failure)?,
+                "params.output_format",
+            ),
+        }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 #[serde(remote = "Variant")]
 enum MyVariant {
     Argon2d = 0,
@@ -72,24 +75,33 @@ enum MyVariant {
     Argon2id = 2,
 }
 
-#[derive(Deserialize)]
+/// "encoded" for the PHC string, any other [`OutputEncoding`] encodes the bare hash.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum Argon2OutputFormat {
+    Encoded(Encoded),
+    Hash(OutputEncoding),
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-enum OutputFormat {
+enum Encoded {
     Encoded,
-    Hex,
-    Raw,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
 struct Params {
     salt: String,
     iterations: u32,
     memory: u32,
     parallelism: u32,
+    #[validate(maximum = 1024)]
+    #[validate(minimum = 4)]
+    #[schemars(range(min = 4, max = 1024))]
     hash_length: u32,
     #[serde(with = "MyVariant")]
     argon2_type: Variant,
-    output_format: OutputFormat,
+    output_format: Argon2OutputFormat,
 }
 
 create_me_daddy!();
@@ -111,16 +123,17 @@ create_me_daddy!();
 ///         "parallelism": u32,
 ///         "hash_length": u32,
 ///         "argon2_type": Argon2Type,
-///         "output_format": OutputFormat,
+///         "output_format": Argon2OutputFormat,
 ///         "memory": u32
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - u32 is unsigned 32-bit integer
+///     - hash_length is the length of the hash in bytes, from 4 to 1024
 ///     - SaltFormat is enum of "utf8", "hex", "base64", "latin1"
 ///     - Argon2Type is enum of "Argon2i", "Argon2d", "Argon2id"
-///     - OutputFormat is enum of "encoded", "hex", "raw"
+///     - Argon2OutputFormat is "encoded" or any OutputEncoding of the bare hash, see [`OutputEncoding`](crate::OutputEncoding)
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -131,7 +144,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -176,7 +189,7 @@ create_me_daddy!();
 /// ```
 /// ```http
 /// {
-///   "Ok": "hex"
+///   "Ok": "eaecfd667dc120e1b90edf0b9cd5bcf57ab325b38160bb64d66c8d738b5951959b74"
 /// }
 /// ```
 /// ## №3
@@ -198,7 +211,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `iterations`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.iterations",
+///     "message": {
+///       "en": "Missing field `params.iterations`.",
+///       "ru": "Отсутствует обязательное поле `params.iterations`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Argon2;
@@ -1,27 +1,27 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    create_info_struct, create_me_daddy, libs::ciphers::affine_cipher_encode,
-    utils::SupportedLanguages, Operation, DOCS_URL,
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, ciphers::affine_cipher_encode},
+    Operation, OperationError, DOCS_URL,
 };
 
 impl Operation<'_, DeserializeMeDaddy, String> for AtbashCipher {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet) = (
+            request.input.into_string()?,
+            request.params.lang.alphabet()?,
+        );
+        let last = alphabet.length() as i16 - 1;
 
-        let (input, lang) = (request.input, request.params.lang);
-
-        match lang {
-            SupportedLanguages::EN => affine_cipher_encode(&input, lang, 25, 25),
-            SupportedLanguages::RU => affine_cipher_encode(&input, lang, 31, 31),
-            SupportedLanguages::RU_WITH_YO => affine_cipher_encode(&input, lang, 32, 32),
-        }
+        affine_cipher_encode(&input, &alphabet, last, last)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
-    lang: SupportedLanguages,
+    lang: Language,
 }
 
 create_me_daddy!();
@@ -38,12 +38,12 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguage,
+///         "lang": Language,
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -54,7 +54,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -79,15 +79,15 @@ create_me_daddy!();
 /// POST /api/AtbashCipher
 ///
 /// {
-///     "input": "Привет!",
+///     "input": "Привіт, світе!",
 ///     "params": {
-///         "lang": "ru",
+///         "lang": "uk",
 ///     }
 /// }
 /// ```
 /// ```http
 /// {
-///   "Ok": "Рпчэън!"
+///   "Ok": "Йїтьси, іьсиц!"
 /// }
 /// ```
 /// ## №3
@@ -103,7 +103,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `lang`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Missing field `params.lang`.",
+///       "ru": "Отсутствует обязательное поле `params.lang`."
+///     }
+///   }
 /// }
 /// ```
 pub struct AtbashCipher;
@@ -0,0 +1,138 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, vigenere_trait::VigenereCipher},
+    utils::sub,
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for AutokeyCipherDecode {}
+
+impl Operation<'_, DeserializeMeDaddy, String> for AutokeyCipherDecode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+        );
+        <Self as VigenereCipher>::autokey_cipher(&lang, &key, &input, sub, true)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    key: String,
+}
+
+create_me_daddy!();
+
+/// The Autokey cipher is a Vigenere cipher whose key is followed by the plaintext itself instead of being repeated.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Autokey_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AutokeyCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "key": String
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "Rijss, Hzfhr!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "Щыжщфв, Фкх!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Привет, Мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "Привет, Мир!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.key",
+///     "message": {
+///       "en": "Invalid key.",
+///       "ru": "Неверный ключ."
+///     }
+///   }
+/// }
+/// ```
+pub struct AutokeyCipherDecode;
+
+const NAME: &str = "AutokeyCipherDecode";
+const DESCRIPTION_EN: &str = "The Autokey cipher is a Vigenere cipher whose key is followed by the plaintext itself instead of being repeated.";
+const DESCRIPTION_RU: &str = "Шифр с автоключом — шифр Виженера, ключ которого продолжается самим открытым текстом, а не повторяется.";
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Autokey_cipher");
+
+create_info_struct!(
+    AutokeyCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
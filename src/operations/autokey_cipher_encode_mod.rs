@@ -0,0 +1,138 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, vigenere_trait::VigenereCipher},
+    utils::add,
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for AutokeyCipherEncode {}
+
+impl Operation<'_, DeserializeMeDaddy, String> for AutokeyCipherEncode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+        );
+        <Self as VigenereCipher>::autokey_cipher(&lang, &key, &input, add, false)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    key: String,
+}
+
+create_me_daddy!();
+
+/// The Autokey cipher is a Vigenere cipher whose key is followed by the plaintext itself instead of being repeated.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Autokey_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AutokeyCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "key": String
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Rijss, Hzfhr!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "Привет, Мир!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Щыжщфв, Фкх!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_input",
+///     "param": "input",
+///     "message": {
+///       "en": "Input is empty.",
+///       "ru": "Входные данные пусты."
+///     }
+///   }
+/// }
+/// ```
+pub struct AutokeyCipherEncode;
+
+const NAME: &str = "AutokeyCipherEncode";
+const DESCRIPTION_EN: &str = "The Autokey cipher is a Vigenere cipher whose key is followed by the plaintext itself instead of being repeated.";
+const DESCRIPTION_RU: &str = "Шифр с автоключом — шифр Виженера, ключ которого продолжается самим открытым текстом, а не повторяется.";
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Autokey_cipher");
+
+create_info_struct!(
+    AutokeyCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
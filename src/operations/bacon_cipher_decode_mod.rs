@@ -1,15 +1,17 @@
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
-    utils::SupportedLanguages,
-    Operation, DOCS_URL,
+    libs::{
+        alphabet::Language,
+        bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
+    },
+    Operation, OperationError, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::mem::swap;
 
 impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherDecode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
         let (
             input,
             Params {
@@ -18,7 +20,7 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherDecode {
                 invert_translation,
                 lang,
             },
-        ) = (request.input, request.params);
+        ) = (request.input.into_string()?, request.params);
 
         let (mut a, mut b) = match translation {
             SupportedBaconTranslation::ZeroOne => ('0', '1'),
@@ -29,7 +31,7 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherDecode {
             swap(&mut a, &mut b);
         }
 
-        let cipher = BaconCipher::new(a, b, translation, bacon_alphabet, lang);
+        let cipher = BaconCipher::new(a, b, translation, bacon_alphabet, &lang.alphabet()?);
 
         let output = cipher.decode(&input).join("");
 
@@ -39,12 +41,12 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherDecode {
 
 create_me_daddy!();
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     bacon_alphabet: SupportedBaconAlphabet,
     translation: SupportedBaconTranslation,
     invert_translation: bool,
-    lang: SupportedLanguages,
+    lang: Language,
 }
 
 /// Bacon's cipher or the Baconian cipher is a method of steganography devised by Francis Bacon in 1605. A message is concealed in the presentation of text, rather than its content.
@@ -63,14 +65,14 @@ struct Params {
 ///         bacon_alphabet: SupportedBaconAlphabet,
 ///         translation: SupportedBaconTranslation,
 ///         invert_translation: bool,
-///         lang: SupportedLanguages,
+///         lang: Language,
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedBaconAlphabet is enum of "Standard", "Complete"
 ///     - SupportedBaconTranslation is enum of "0/1", "A/B"
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo"
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -81,7 +83,7 @@ struct Params {
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -130,16 +132,23 @@ struct Params {
 /// {
 ///     "input": "missing `lang`",
 ///     "params": {
-///         bacon_alphabet: "Standard",
-///         translation: "0/1",
-///         invert_translation: false,
+///         "bacon_alphabet": "Standard",
+///         "translation": "0/1",
+///         "invert_translation": false
 ///     }
 /// }
 /// ```
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `lang`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Missing field `params.lang`.",
+///       "ru": "Отсутствует обязательное поле `params.lang`."
+///     }
+///   }
 /// }
 /// ```
 pub struct BaconCipherDecode;
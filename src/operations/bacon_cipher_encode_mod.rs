@@ -1,15 +1,17 @@
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
-    utils::SupportedLanguages,
-    Operation, DOCS_URL,
+    libs::{
+        alphabet::Language,
+        bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
+    },
+    Operation, OperationError, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::mem::swap;
 
 impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherEncode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
         let (
             input,
             Params {
@@ -19,7 +21,7 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherEncode {
                 invert_translation,
                 lang,
             },
-        ) = (request.input, request.params);
+        ) = (request.input.into_string()?, request.params);
 
         let (mut a, mut b) = match translation {
             SupportedBaconTranslation::ZeroOne => ('0', '1'),
@@ -30,7 +32,7 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherEncode {
             swap(&mut a, &mut b);
         }
 
-        let cipher = BaconCipher::new(a, b, translation, bacon_alphabet, lang);
+        let cipher = BaconCipher::new(a, b, translation, bacon_alphabet, &lang.alphabet()?);
 
         let output = cipher.encode(&input);
 
@@ -52,13 +54,13 @@ impl Operation<'_, DeserializeMeDaddy, String> for BaconCipherEncode {
 
 create_me_daddy!();
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     bacon_alphabet: SupportedBaconAlphabet,
     translation: SupportedBaconTranslation,
     keep_extra_character: bool,
     invert_translation: bool,
-    lang: SupportedLanguages,
+    lang: Language,
 }
 
 /// Bacon's cipher or the Baconian cipher is a method of steganography devised by Francis Bacon in 1605. A message is concealed in the presentation of text, rather than its content.
@@ -78,14 +80,14 @@ struct Params {
 ///         translation: SupportedBaconTranslation,
 ///         keep_extra_character: bool,
 ///         invert_translation: bool,
-///         lang: SupportedLanguages,
+///         lang: Language,
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedBaconAlphabet is enum of "Standard", "Complete"
 ///     - SupportedBaconTranslation is enum of "0/1", "A/B"
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo"
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -96,7 +98,7 @@ struct Params {
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -147,17 +149,24 @@ struct Params {
 /// {
 ///     "input": "missing `lang`",
 ///     "params": {
-///         bacon_alphabet: "Standard",
-///         translation: "0/1",
-///         keep_extra_character: true,
-///         invert_translation: false,
+///         "bacon_alphabet": "Standard",
+///         "translation": "0/1",
+///         "keep_extra_character": true,
+///         "invert_translation": false
 ///     }
 /// }
 /// ```
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `lang`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Missing field `params.lang`.",
+///       "ru": "Отсутствует обязательное поле `params.lang`."
+///     }
+///   }
 /// }
 /// ```
 pub struct BaconCipherEncode;
@@ -1,21 +1,34 @@
-use crate::{create_info_struct, create_me_daddy, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, policy, utils::DataRepresentationInput, Operation,
+    OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for BcryptCompare {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, encoded_hash) = (request.input, request.params.encoded_hash);
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let res = bcrypt::verify(input.as_bytes(), &encoded_hash).map_err(|err| err.to_string())?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, encoded_hash) = (request.input.into_bytes(), request.params.encoded_hash);
+        if let Ok(parts) = encoded_hash.parse::<bcrypt::HashParts>() {
+            policy::check_cost("params.encoded_hash", parts.get_cost(), |policy| {
+                policy.max_bcrypt_cost
+            })?;
+        }
+
+        let res = bcrypt::verify(&input, &encoded_hash).map_err(OperationError::crypto_failure)?;
 
         match res {
-            true => Ok(format!("Match `{}`.", input)),
-            false => Err("No match.".to_string()),
+            true => Ok(format!("Match `{}`.", String::from_utf8_lossy(&input))),
+            false => Err(OperationError::invalid_input(
+                "No match.",
+                "Нет совпадения.",
+            )),
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     encoded_hash: String,
 }
@@ -49,7 +62,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -98,10 +111,16 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `encoded_hash`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.encoded_hash",
+///     "message": {
+///       "en": "Missing field `params.encoded_hash`.",
+///       "ru": "Отсутствует обязательное поле `params.encoded_hash`."
+///     }
+///   }
 /// }
 /// ```
-
 pub struct BcryptCompare;
 
 const NAME: &str = "BcryptCompare";
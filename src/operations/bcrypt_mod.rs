@@ -1,22 +1,39 @@
-use crate::{create_info_struct, create_me_daddy, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, policy, utils::DataRepresentationInput, Operation,
+    OperationError, DOCS_URL,
+};
 use bcrypt::Version;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
 
 impl Operation<'_, DeserializeMeDaddy, String> for Bcrypt {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, rounds, version) =
-            (request.input, request.params.rounds, request.params.version);
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, rounds, version) = (
+            request.input.into_bytes(),
+            request.params.rounds,
+            request.params.version,
+        );
+        policy::check_cost("params.rounds", rounds, |policy| policy.max_bcrypt_cost)?;
 
         let res =
-            bcrypt::hash_with_result(input.as_bytes(), rounds).map_err(|err| err.to_string())?;
-        Ok(res.format_for_version(version).to_string())
+            bcrypt::hash_with_result(input, rounds).map_err(OperationError::crypto_failure)?;
+        Ok(res.format_for_version(version.into()).to_string())
     }
 }
 
-#[derive(Deserialize)]
-#[serde(remote = "Version")]
+#[derive(Clone, Copy, Deserialize, JsonSchema)]
 pub enum MyVersion {
     #[serde(rename = "2a")]
     TwoA,
@@ -28,13 +45,24 @@ pub enum MyVersion {
     TwoB,
 }
 
-#[derive(Deserialize, Validate)]
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
 struct Params {
     #[validate(maximum = 31)]
     #[validate(minimum = 4)]
+    #[schemars(range(min = 4, max = 31))]
     rounds: u32,
-    #[serde(with = "MyVersion")]
-    version: Version,
+    version: MyVersion,
+}
+
+impl From<MyVersion> for Version {
+    fn from(version: MyVersion) -> Self {
+        match version {
+            MyVersion::TwoA => Version::TwoA,
+            MyVersion::TwoX => Version::TwoX,
+            MyVersion::TwoY => Version::TwoY,
+            MyVersion::TwoB => Version::TwoB,
+        }
+    }
 }
 
 create_me_daddy!();
@@ -70,7 +98,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -122,7 +150,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `version`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.version",
+///     "message": {
+///       "en": "Missing field `params.version`.",
+///       "ru": "Отсутствует обязательное поле `params.version`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Bcrypt;
@@ -1,12 +1,16 @@
-use crate::{create_info_struct, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, impl_operation_request,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
 use bcrypt::BcryptError;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
 
 impl Operation<'_, DeserializeMeDaddy, HashParts> for BcryptParse {
-    fn do_black_magic(&self, request: &str) -> Result<HashParts, String> {
-        let request = self.validate(request)?;
-        let hash = request.hash;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<HashParts, OperationError> {
+        let hash = request.input.into_string()?;
 
         let mut parts = HashParts {
             cost: 0,
@@ -17,7 +21,7 @@ impl Operation<'_, DeserializeMeDaddy, HashParts> for BcryptParse {
         let raw_parts: Vec<_> = hash.split('$').filter(|s| !s.is_empty()).collect();
 
         if raw_parts.len() != 3 {
-            return Err(BcryptError::InvalidHash(hash.to_string()).to_string());
+            return Err(invalid_hash(BcryptError::InvalidHash(hash.to_string())));
         }
 
         if raw_parts[0] != "2y"
@@ -25,31 +29,43 @@ impl Operation<'_, DeserializeMeDaddy, HashParts> for BcryptParse {
             && raw_parts[0] != "2a"
             && raw_parts[0] != "2x"
         {
-            return Err(BcryptError::InvalidPrefix(raw_parts[0].to_string()).to_string());
+            return Err(invalid_hash(BcryptError::InvalidPrefix(
+                raw_parts[0].to_string(),
+            )));
         }
 
         if let Ok(c) = raw_parts[1].parse::<u32>() {
             parts.cost = c;
         } else {
-            return Err(BcryptError::InvalidCost(raw_parts[1].to_string()).to_string());
+            return Err(invalid_hash(BcryptError::InvalidCost(
+                raw_parts[1].to_string(),
+            )));
         }
 
         if raw_parts[2].len() == 53 && raw_parts[2].is_char_boundary(22) {
             parts.salt = raw_parts[2][..22].chars().collect();
             parts.hash = raw_parts[2][22..].chars().collect();
         } else {
-            return Err(BcryptError::InvalidHash(hash.to_string()).to_string());
+            return Err(invalid_hash(BcryptError::InvalidHash(hash.to_string())));
         }
 
         Ok(parts)
     }
 }
 
-#[derive(Deserialize)]
+fn invalid_hash(err: BcryptError) -> OperationError {
+    OperationError::invalid_input(err.to_string(), format!("Некорректный хеш bcrypt: {err}."))
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    hash: String,
+    #[serde(alias = "hash")]
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
 }
 
+impl_operation_request!();
+
 #[derive(Serialize, Validate)]
 pub struct HashParts {
     #[validate(maximum = 31)]
@@ -70,9 +86,10 @@ pub struct HashParts {
 /// Send POST requests to /api/BcryptParse with your data using json payload with this structure
 /// ``` json
 /// {
-///     "hash": string,
+///     "input": string,
 /// }
 /// ```
+/// `hash` is accepted as an alias of `input`.
 /// <br/><br/>
 ///
 /// ## Server response have two possible formats
@@ -91,7 +108,7 @@ pub struct HashParts {
 ///     - i31 is signed digit between 4 and 31
 /// ### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -139,7 +156,14 @@ pub struct HashParts {
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `hash`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "input",
+///     "message": {
+///       "en": "Missing field `input`.",
+///       "ru": "Отсутствует обязательное поле `input`."
+///     }
+///   }
 /// }
 /// ```
 pub struct BcryptParse;
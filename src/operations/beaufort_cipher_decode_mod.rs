@@ -0,0 +1,150 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, vigenere_trait::VigenereCipher},
+    utils::add,
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for BeaufortCipherDecode {}
+
+impl Operation<'_, DeserializeMeDaddy, String> for BeaufortCipherDecode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key, variant) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+            request.params.variant.unwrap_or(false),
+        );
+
+        match variant {
+            true => <Self as VigenereCipher>::cipher(&lang, &key, &input, add),
+            false => <Self as VigenereCipher>::cipher(&lang, &key, &input, |text, key| key - text),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    key: String,
+    #[schemars(extend("default" = false))]
+    variant: Option<bool>,
+}
+
+create_me_daddy!();
+
+/// The Beaufort cipher is a polyalphabetic substitution cipher similar to the Vigenere cipher, every letter is replaced by the key letter minus the plaintext letter, which makes the cipher its own inverse. The variant Beaufort subtracts the key letter from the plaintext letter instead.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Beaufort_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/BeaufortCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "key": String,
+///         "variant": Option<bool>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+///     - variant switches to the variant Beaufort, `false` by default
+///     - without variant decoding is the same as encoding
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Danzq, Cwnnh!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Ееклыз, Осж!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "ключ",
+///         "variant": true
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Привет, Мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key",
+///         "variant": "yes"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.variant",
+///     "message": {
+///       "en": "Invalid value of `params.variant`: invalid type: string \"yes\", expected a boolean.",
+///       "ru": "Недопустимое значение параметра `params.variant`: invalid type: string \"yes\", expected a boolean."
+///     }
+///   }
+/// }
+/// ```
+pub struct BeaufortCipherDecode;
+
+const NAME: &str = "BeaufortCipherDecode";
+const DESCRIPTION_EN: &str = "The Beaufort cipher is a polyalphabetic substitution cipher similar to the Vigenere cipher, every letter is replaced by the key letter minus the plaintext letter, which makes the cipher its own inverse. The variant Beaufort subtracts the key letter from the plaintext letter instead.";
+const DESCRIPTION_RU: &str = "Шифр Бофорта — полиалфавитный шифр замены, похожий на шифр Виженера, каждая буква заменяется разностью буквы ключа и буквы открытого текста, поэтому шифр обратен сам себе. Вариант Бофорта, наоборот, вычитает букву ключа из буквы открытого текста.";
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Beaufort_cipher");
+
+create_info_struct!(
+    BeaufortCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
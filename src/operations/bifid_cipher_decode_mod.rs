@@ -0,0 +1,138 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, ciphers::bifid_cipher_decode},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, String> for BifidCipherDecode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet, keyword) = (
+            request.input.into_string()?,
+            request.params.lang.alphabet()?,
+            request.params.keyword,
+        );
+
+        bifid_cipher_decode(&input, &alphabet, &keyword)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    keyword: String,
+}
+
+create_me_daddy!();
+
+/// The Bifid cipher is a cipher which uses a Polybius square in conjunction with transposition, which can be fairly difficult to decipher without knowing the alphabet keyword.
+/// <br><br/>
+/// For more information about cipher/hash_function go [here](https://wikipedia.org/wiki/Bifid_cipher)
+/// For more information about this function go [here](DOCS_URL)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/BifidCipherDecode with your data using json payload with this structure
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "keyword": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+///     - squares that don't fit the alphabet exactly are padded with ",.-=0123456789", letters in place of that padding are decoded lowercase
+/// <br/><br/>
+///
+/// ## Server response have two possible formats
+///
+/// ### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// ### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "Cfosb, Lzwyv!",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "Schrodinger"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "суййтишм дчурмндцъ жь нруцсвщ",
+///     "params": {
+///         "lang": "ru",
+///         "keyword": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "тестовое сообщение на русском"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "суййтишм дчурмндцъ жь нруцсвщ",
+///     "params": {
+///         "lang": "ru"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.keyword",
+///     "message": {
+///       "en": "Missing field `params.keyword`.",
+///       "ru": "Отсутствует обязательное поле `params.keyword`."
+///     }
+///   }
+/// }
+/// ```
+pub struct BifidCipherDecode;
+
+const NAME: &str = "BifidCipherDecode";
+const DESCRIPTION_EN: &str = "The Bifid cipher is a cipher which uses a Polybius square in conjunction with transposition, which can be fairly difficult to decipher without knowing the alphabet keyword.";
+const DESCRIPTION_RU: &str = "Шифр Бифида - это шифр, в котором используется квадрат Полибия в сочетании с транспозицией, которую довольно сложно расшифровать, не зная ключевого слова алфавита.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Bifid_cipher");
+
+create_info_struct!(
+    BifidCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,101 +1,27 @@
-use itertools::Itertools;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::ciphers::gen_polybius_square,
-    regex_check,
-    traits::CharTrait,
-    utils::{get_alphabet, SupportedLanguages},
-    Operation, DOCS_URL,
+    libs::{alphabet::Language, ciphers::bifid_cipher_encode},
+    Operation, OperationError, DOCS_URL,
 };
 
 impl Operation<'_, DeserializeMeDaddy, String> for BifidCipherEncode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, lang, keyword) = (request.input, request.params.lang, request.params.keyword);
-        let keyword_str = match lang {
-            SupportedLanguages::EN => keyword.to_uppercase().replace('J', "I"),
-            SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => keyword.to_uppercase(),
-        };
-        let keyword: String = keyword_str.chars().dedup().collect();
-        let (_, _, _, _, _, reg) = get_alphabet(&lang);
-        if !regex_check!(reg => &keyword_str) && keyword.is_empty() {
-            return Err("The key must consist only of your alphabets characters".to_string());
-        }
-
-        let (input, size) = match lang {
-            SupportedLanguages::EN => (input.replace('J', "I").replace('j', "i"), 5),
-            SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => (input, 6),
-        };
-
-        let mut x_cord: Vec<usize> = Vec::new();
-        let mut y_cord: Vec<usize> = Vec::new();
-        let mut other: HashMap<usize, char> = HashMap::new();
-        let mut case: Vec<bool> = Vec::new();
-        let mut output = String::new();
-
-        let polybius = gen_polybius_square(&lang, &keyword_str);
-
-        for (i, letter) in input.chars().enumerate() {
-            case.push(letter.is_lowercase());
-
-            let idx = polybius
-                .iter()
-                .position(|&x| x == letter.to_upper_case())
-                .unwrap_or(usize::MAX);
-
-            if idx == usize::MAX {
-                other.insert(i, letter);
-                continue;
-            }
-
-            x_cord.push((idx as f64 / size as f64).ceil() as usize - 1);
-            y_cord.push(idx % size);
-        }
-
-        x_cord.extend(y_cord);
-        let mut kludge = false;
-        let mut i = 0;
-        let mut case_idx = 0;
-        let input_len = input.chars().count();
-
-        while output.chars().count() != input_len {
-            if let Some(ch) = other.get(&output.chars().count()) {
-                output.push(match case[case_idx] {
-                    true => ch.to_lower_case(),
-                    false => *ch,
-                });
-                case_idx += 1;
-                continue;
-            }
-
-            if kludge {
-                kludge = !kludge;
-                continue;
-            }
-
-            let letter = polybius[x_cord[i] * size + x_cord[i + 1]];
-            output.push({
-                let out = match case[case_idx] {
-                    true => letter.to_lower_case(),
-                    false => letter,
-                };
-                case_idx += 1;
-                out
-            });
-            kludge = !kludge;
-            i += 2;
-        }
-
-        Ok(output)
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet, keyword) = (
+            request.input.into_string()?,
+            request.params.lang.alphabet()?,
+            request.params.keyword,
+        );
+
+        bifid_cipher_encode(&input, &alphabet, &keyword)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
-    lang: SupportedLanguages,
+    lang: Language,
     keyword: String,
 }
 
@@ -114,13 +40,13 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages,
+///         "lang": Language,
 ///         "keyword": string
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ## Server response have two possible formats
@@ -131,7 +57,7 @@ create_me_daddy!();
 /// ```
 /// ### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -166,7 +92,7 @@ create_me_daddy!();
 /// ```
 /// ```http
 /// {
-///   "Ok": "ртйксйцк еатслсм,э оя чънрчкю"
+///   "Ok": "суййтишм дчурмндцъ жь нруцсвщ"
 /// }
 /// ```
 /// ## №3
@@ -176,14 +102,21 @@ create_me_daddy!();
 /// {
 ///     "input": "тестовое сообщение на русском",
 ///     "params": {
-///         "lang": "ru",
+///         "lang": "ru"
 ///     }
 /// }
 /// ```
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `keyword`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.keyword",
+///     "message": {
+///       "en": "Missing field `params.keyword`.",
+///       "ru": "Отсутствует обязательное поле `params.keyword`."
+///     }
+///   }
 /// }
 /// ```
 pub struct BifidCipherEncode;
@@ -1,20 +1,18 @@
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::base64::to_base64,
-    utils::{convert_to_byte_array, to_hex, SupportedFormats},
-    Operation, OutputFormat, DOCS_URL,
-};
-use blake2::{
-    digest::{Update, VariableOutput},
-    VarBlake2b,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{convert_to_byte_array, DataRepresentation, DataRepresentationInput, SupportedFormats},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-impl Operation<'_, DeserializeMeDaddy, OutputFormat> for Blake2b {
-    fn do_black_magic(&self, request: &str) -> Result<OutputFormat, String> {
-        let request = self.validate(request)?;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Blake2b {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, size, key, key_format, output_format) = (
-            request.input,
+            request.input.into_bytes(),
             request.params.size,
             request.params.key,
             request.params.key_format,
@@ -25,36 +23,40 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for Blake2b {
             None => Vec::new(),
             Some(key) => match key_format {
                 None => {
-                    return Err("Key format argument must be set.".to_string());
+                    return Err(OperationError::MissingField {
+                        param: "params.key_format".to_string(),
+                    });
                 }
-                Some(key_format) => convert_to_byte_array(&key, &key_format)?,
+                Some(key_format) => convert_to_byte_array(&key, &key_format).map_err(|err| {
+                    OperationError::invalid_parameter(
+                        "params.key",
+                        format!("Invalid key: {err}."),
+                        format!("Неверный ключ: {err}."),
+                    )
+                })?,
             },
         };
 
-        let mut hasher = VarBlake2b::new_keyed(
-            &key,
-            match size {
+        let mut hasher = Hasher::keyed(
+            HashAlgorithm::Blake2b(match size {
                 SupportedBlake2bSize::Blake2b128 => 16,
                 SupportedBlake2bSize::Blake2b160 => 20,
                 SupportedBlake2bSize::Blake2b256 => 32,
                 SupportedBlake2bSize::Blake2b384 => 48,
                 SupportedBlake2bSize::Blake2b512 => 64,
-            },
-        );
+            }),
+            &key,
+        )?;
 
-        hasher.update(input.as_bytes());
+        hasher.update(&input);
 
-        let res = hasher.finalize_boxed();
+        let res = hasher.finalize();
 
-        Ok(match output_format {
-            SupportedOutputFormat::Hex => OutputFormat::Hex(to_hex(&res)),
-            SupportedOutputFormat::Base64 => OutputFormat::Base64(to_base64(&res, None)?),
-            SupportedOutputFormat::Uint8Array => OutputFormat::Uint8Array(res.to_vec()),
-        })
+        output_format.encode_tagged(res, "params.output_format")
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 enum SupportedBlake2bSize {
     #[serde(rename = "128")]
     Blake2b128,
@@ -68,20 +70,12 @@ enum SupportedBlake2bSize {
     Blake2b512,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedOutputFormat {
-    Hex,
-    Base64,
-    Uint8Array,
-}
-
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     key: Option<String>,
     key_format: Option<SupportedFormats>,
     size: SupportedBlake2bSize,
-    output_format: SupportedOutputFormat,
+    output_format: OutputEncoding,
 }
 
 create_me_daddy!();
@@ -101,15 +95,16 @@ create_me_daddy!();
 ///         "key": Option<String>,
 ///         "key_format": Option<SupportedFormats>,
 ///         "size": SupportedBlake2bSize,
-///         "output_format": SupportedOutputFormat
+///         "output_format": OutputEncoding
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - Option<String> is optional argument with type of string
-///     - Option<SupportedFormats> is optional enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Option<SupportedFormats> is optional enum of "binary", "utf8", "hex", "base64", "latin1", "decimal"
 ///     - SupportedBlake2bSize is enum of "128", "160", "256", "384", "512"
-///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
+///     - OutputEncoding is "hex", "base64", "uint8array", "raw" or an object with options, see [`OutputEncoding`](crate::OutputEncoding).
+///       The bare "hex", "base64" and "uint8array" names answer with an object like `{ "hex": ... }`, the rest with the encoded value itself
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -122,9 +117,10 @@ create_me_daddy!();
 ///   }
 /// }
 /// ```
+/// or `{ "Ok": `some answer` }` for the other formats.
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -136,6 +132,7 @@ create_me_daddy!();
 ///     "params": {
 ///         "size": "160",
 ///         "key": "super secret key",
+///         "key_format": "utf8",
 ///         "output_format": "base64"
 ///     }
 /// }
@@ -143,9 +140,9 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 200 Ok
 /// {
-///     "Ok": {
-///         "base64": "ktyh3oJo3OG/51S6SrnE6lzvSuk="
-///     }
+///   "Ok": {
+///     "base64": "ktyh3oJo3OG/51S6SrnE6lzvSuk="
+///   }
 /// }
 /// ```
 /// ## №2
@@ -156,15 +153,13 @@ create_me_daddy!();
 ///     "input": "hello",
 ///     "params": {
 ///         "size": "128",
-///         "output_format": "hex"
+///         "output_format": { "format": "hex", "delimiter": "Space", "uppercase": true }
 ///     }
 /// }
 /// ```
 /// ```http
 /// {
-///     "Ok": {
-///         "hex": "46fb7408d4f285228f4af516ea25851b"
-///     }
+///   "Ok": "46 FB 74 08 D4 F2 85 22 8F 4A F5 16 EA 25 85 1B"
 /// }
 /// ```
 /// ## №3
@@ -175,7 +170,7 @@ create_me_daddy!();
 ///     "input": "hello",
 ///     "params": {
 ///         "size": "128",
-///         "key": "key format not set"
+///         "key": "key format not set",
 ///         "output_format": "hex"
 ///     }
 /// }
@@ -183,7 +178,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Key format argument must be set."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.key_format",
+///     "message": {
+///       "en": "Missing field `params.key_format`.",
+///       "ru": "Отсутствует обязательное поле `params.key_format`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Blake2b;
@@ -1,21 +1,18 @@
-use crate::OutputFormat;
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::base64::to_base64,
-    utils::{convert_to_byte_array, to_hex, SupportedFormats},
-    Operation, DOCS_URL,
-};
-use blake2::{
-    digest::{Update, VariableOutput},
-    VarBlake2s,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{convert_to_byte_array, DataRepresentation, DataRepresentationInput, SupportedFormats},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-impl Operation<'_, DeserializeMeDaddy, OutputFormat> for Blake2s {
-    fn do_black_magic(&self, request: &str) -> Result<OutputFormat, String> {
-        let request = self.validate(request)?;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Blake2s {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, size, key, key_format, output_format) = (
-            request.input,
+            request.input.into_bytes(),
             request.params.size,
             request.params.key,
             request.params.key_format,
@@ -26,36 +23,40 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for Blake2s {
             None => Vec::new(),
             Some(key) => match key_format {
                 None => {
-                    return Err("Key format argument must be set.".to_string());
+                    return Err(OperationError::MissingField {
+                        param: "params.key_format".to_string(),
+                    });
                 }
-                Some(key_format) => convert_to_byte_array(&key, &key_format)?,
+                Some(key_format) => convert_to_byte_array(&key, &key_format).map_err(|err| {
+                    OperationError::invalid_parameter(
+                        "params.key",
+                        format!("Invalid key: {err}."),
+                        format!("Неверный ключ: {err}."),
+                    )
+                })?,
             },
         };
 
-        let mut hasher = VarBlake2s::new_keyed(
-            &key,
-            match size {
+        let mut hasher = Hasher::keyed(
+            HashAlgorithm::Blake2s(match size {
                 SupportedBlake2sSize::Blake2s128 => 16,
                 SupportedBlake2sSize::Blake2s160 => 20,
                 SupportedBlake2sSize::Blake2s256 => 32,
                 SupportedBlake2sSize::Blake2s384 => 48,
                 SupportedBlake2sSize::Blake2s512 => 64,
-            },
-        );
+            }),
+            &key,
+        )?;
 
-        hasher.update(input.as_bytes());
+        hasher.update(&input);
 
-        let res = hasher.finalize_boxed();
+        let res = hasher.finalize();
 
-        Ok(match output_format {
-            SupportedOutputFormat::Hex => OutputFormat::Hex(to_hex(&res)),
-            SupportedOutputFormat::Base64 => OutputFormat::Base64(to_base64(&res, None)?),
-            SupportedOutputFormat::Uint8Array => OutputFormat::Uint8Array(res.to_vec()),
-        })
+        output_format.encode_tagged(res, "params.output_format")
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 enum SupportedBlake2sSize {
     #[serde(rename = "128")]
     Blake2s128,
@@ -69,19 +70,12 @@ enum SupportedBlake2sSize {
     Blake2s512,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedOutputFormat {
-    Hex,
-    Base64,
-    Uint8Array,
-}
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     key: Option<String>,
     key_format: Option<SupportedFormats>,
     size: SupportedBlake2sSize,
-    output_format: SupportedOutputFormat,
+    output_format: OutputEncoding,
 }
 
 create_me_daddy!();
@@ -101,15 +95,16 @@ create_me_daddy!();
 ///         "key": Option<String>,
 ///         "key_format": Option<SupportedFormats>,
 ///         "size": SupportedBlake2sSize,
-///         "output_format": SupportedOutputFormat
+///         "output_format": OutputEncoding
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - Option<String> is optional argument with type of string
-///     - Option<SupportedFormats> is optional enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Option<SupportedFormats> is optional enum of "binary", "utf8", "hex", "base64", "latin1", "decimal"
 ///     - SupportedBlake2sSize is enum of "128", "160", "256", "384", "512"
-///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
+///     - OutputEncoding is "hex", "base64", "uint8array", "raw" or an object with options, see [`OutputEncoding`](crate::OutputEncoding).
+///       The bare "hex", "base64" and "uint8array" names answer with an object like `{ "hex": ... }`, the rest with the encoded value itself
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -122,9 +117,10 @@ create_me_daddy!();
 ///   }
 /// }
 /// ```
+/// or `{ "Ok": `some answer` }` for the other formats.
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -144,9 +140,9 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 200 Ok
 /// {
-///     "Ok": {
-///         "base64": "7Y12BJIsWVVt5Oj36MRs0OPzN4o="
-///     }
+///   "Ok": {
+///     "base64": "7Y12BJIsWVVt5Oj36MRs0OPzN4o="
+///   }
 /// }
 /// ```
 /// ## №2
@@ -163,9 +159,9 @@ create_me_daddy!();
 /// ```
 /// ```http
 /// {
-///     "Ok": {
-///         "hex": "96d539653dbf841c384b53d5f04658e5"
-///     }
+///   "Ok": {
+///     "hex": "96d539653dbf841c384b53d5f04658e5"
+///   }
 /// }
 /// ```
 /// ## №3
@@ -176,7 +172,7 @@ create_me_daddy!();
 ///     "input": "hello",
 ///     "params": {
 ///         "size": "128",
-///         "key": "key format not set"
+///         "key": "key format not set",
 ///         "output_format": "hex"
 ///     }
 /// }
@@ -184,7 +180,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Key format argument must be set."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.key_format",
+///     "message": {
+///       "en": "Missing field `params.key_format`.",
+///       "ru": "Отсутствует обязательное поле `params.key_format`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Blake2s;
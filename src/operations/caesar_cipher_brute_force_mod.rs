@@ -0,0 +1,179 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{
+        alphabet::Language,
+        ciphers::caesar_cipher,
+        scoring::{rank_candidates, scored_language, Candidate},
+    },
+    policy, Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, Vec<Candidate<CaesarKey>>> for CaesarCipherBruteForce {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(
+        &self,
+        request: DeserializeMeDaddy,
+    ) -> Result<Vec<Candidate<CaesarKey>>, OperationError> {
+        let (input, lang, limit) = (
+            request.input.into_string()?,
+            scored_language(&request.params.lang)?,
+            request.params.limit.unwrap_or(DEFAULT_LIMIT),
+        );
+        let alphabet = request.params.lang.alphabet()?;
+        let mut candidates = Vec::new();
+        for shift in 0..alphabet.length() {
+            policy::checkpoint()?;
+            let output = caesar_cipher(&input, &alphabet, -(shift as i64));
+            candidates.push((CaesarKey { shift }, output));
+        }
+
+        rank_candidates(candidates, lang, limit)
+    }
+}
+
+const DEFAULT_LIMIT: usize = 5;
+
+/// Shift to send to CaesarCipherDecode.
+#[derive(Serialize)]
+pub struct CaesarKey {
+    shift: usize,
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    lang: Language,
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1), extend("default" = 5))]
+    limit: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Tries every shift of the Caesar cipher and returns the candidates whose letter frequencies are closest to the language, best first. With english the shifts are the amounts of ROT13.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Caesar_cipher#Breaking_the_cipher)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CaesarCipherBruteForce with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "limit": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "latin_digits", candidates are scored by english or russian letter frequencies
+///     - limit is maximum number of candidates, 5 by default
+///     - shift of a candidate is the one to send to CaesarCipherDecode, a lower chi_squared means a closer match
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": [ { "shift": usize, "output": string, "chi_squared": f64 } ] }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CaesarCipherBruteForce
+///
+/// {
+///     "input": "Tlla tl ulhy aol vsk avdly ha zlclu",
+///     "params": {
+///         "lang": "en",
+///         "limit": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": [
+///     { "shift": 7, "output": "Meet me near the old tower at seven", "chi_squared": 18.439811819454746 },
+///     { "shift": 18, "output": "Btti bt ctpg iwt das idltg pi htktc", "chi_squared": 46.62161612525873 },
+///     { "shift": 0, "output": "Tlla tl ulhy aol vsk avdly ha zlclu", "chi_squared": 122.78238594771963 }
+///   ]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CaesarCipherBruteForce
+///
+/// {
+///     "input": "Зцчхкчнсцд мезчхе з фехпк ш цчехуиу суцче",
+///     "params": {
+///         "lang": "ru",
+///         "limit": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": [
+///     { "shift": 5, "output": "Встретимся завтра в парке у старого моста", "chi_squared": 24.408477910245878 },
+///     { "shift": 6, "output": "Брспдсзлрю жябспя б ояпйд т рсяпнвн лнрся", "chi_squared": 78.90334490097877 }
+///   ]
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CaesarCipherBruteForce
+///
+/// {
+///     "input": "Grüße",
+///     "params": {
+///         "lang": "de"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Only english and russian texts can be scored.",
+///       "ru": "Оценивать можно только английские и русские тексты."
+///     }
+///   }
+/// }
+/// ```
+pub struct CaesarCipherBruteForce;
+
+const NAME: &str = "CaesarCipherBruteForce";
+const DESCRIPTION_EN: &str = "Tries every shift of the Caesar cipher and returns the candidates whose letter frequencies are closest to the language, best first. With english the shifts are the amounts of ROT13.";
+const DESCRIPTION_RU: &str = "Перебирает все сдвиги шифра Цезаря и возвращает варианты, частоты букв которых ближе всего к языку, начиная с лучшего. Для английского языка сдвиги совпадают с величиной сдвига ROT13.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Caesar_cipher#Breaking_the_cipher");
+
+create_info_struct!(
+    CaesarCipherBruteForceInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,143 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, ciphers::caesar_cipher},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, String> for CaesarCipherDecode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet, shift) = (
+            request.input.into_string()?,
+            request.params.lang.alphabet()?,
+            request.params.shift,
+        );
+
+        Ok(caesar_cipher(
+            &input,
+            &alphabet,
+            -(shift % alphabet.length() as i64),
+        ))
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    shift: i64,
+}
+
+create_me_daddy!();
+
+/// The Caesar cipher is one of the simplest substitution ciphers. Each letter is replaced by a letter a fixed number of positions further down the alphabet.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Caesar_cipher)
+/// <br><br/>
+/// # How to use
+/// \
+/// Send POST requests to /api/CaesarCipherDecode with your data using json payload with this structure
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "shift": i64
+///     }
+/// }
+/// ```
+/// #### where
+///     - i64 is signed 64-bit integer, letters are shifted to the left when it is positive
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+///     - case and characters out of the alphabet are kept as is
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// <br><br/>
+/// ## №1
+/// ``` http
+/// POST /api/CaesarCipherDecode
+///
+/// {
+///     "input": "Khoor, Zruog!",
+///     "params": {
+///         "lang": "en",
+///         "shift": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CaesarCipherDecode
+///
+/// {
+///     "input": "Тулеих, Плу!",
+///     "params": {
+///         "lang": "ru",
+///         "shift": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Привет, Мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CaesarCipherDecode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "shift": "three"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.shift",
+///     "message": {
+///       "en": "Invalid value of `params.shift`: invalid type: string \"three\", expected i64.",
+///       "ru": "Недопустимое значение параметра `params.shift`: invalid type: string \"three\", expected i64."
+///     }
+///   }
+/// }
+/// ```
+pub struct CaesarCipherDecode;
+
+const NAME: &str = "CaesarCipherDecode";
+const DESCRIPTION_EN: &str = "The Caesar cipher is one of the simplest substitution ciphers. Each letter is replaced by a letter a fixed number of positions further down the alphabet.";
+const DESCRIPTION_RU: &str = "Шифр Цезаря — один из простейших шифров замены. Каждая буква заменяется буквой, находящейся на фиксированное число позиций дальше по алфавиту.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Caesar_cipher");
+
+create_info_struct!(
+    CaesarCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,173 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    operations::jump_mod::{JumpSettings, DEFAULT_MAX_JUMPS},
+    utils::{build_regex, DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for ConditionalJump {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        build_regex(&request.params.regex, false, false, "params.regex")?;
+
+        Ok(DataRepresentation::from_bytes(request.input.into_bytes()))
+    }
+}
+
+impl ConditionalJump {
+    pub(crate) fn settings(&self, params: &Value) -> Result<JumpSettings, OperationError> {
+        let Params {
+            regex,
+            invert_condition,
+            label,
+            max_jumps,
+        } = self
+            .validate(&json!({ "input": "", "params": params }).to_string())?
+            .params;
+
+        Ok(JumpSettings {
+            label,
+            max_jumps: max_jumps.unwrap_or(DEFAULT_MAX_JUMPS),
+            condition: Some((
+                build_regex(&regex, false, false, "params.regex")?,
+                invert_condition.unwrap_or_default(),
+            )),
+        })
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    regex: String,
+    invert_condition: Option<bool>,
+    label: String,
+    #[validate(maximum = 1000)]
+    #[schemars(range(max = 1000), extend("default" = 10))]
+    max_jumps: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Goes to the Label with the given name when the data matches the regular expression, or when it doesn't with `invert_condition`. Every jump step is taken at most `max_jumps` times, after that the recipe continues with the next step.
+/// <br><br/>
+/// The data passes through unchanged, the same happens when the operation is sent on its own.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ConditionalJump with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "regex": string,
+///         "invert_condition": Option<bool>,
+///         "label": string,
+///         "max_jumps": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Option<bool> is type that can be bool or null, false by default.
+///     - Option<usize> is type that can be number from 0 to 1000 or null, 10 by default.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "WVVkV2MySkhPRDA9",
+///     "recipe": [
+///         { "op": "Label", "params": { "name": "top" } },
+///         { "op": "FromBase64", "params": {} },
+///         { "op": "ConditionalJump", "params": { "regex": "^(?:[A-Za-z0-9+/]{4})+(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$", "label": "top" } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "hello",
+///     "steps": [
+///       "WVVkV2MySkhPRDA9",
+///       "YUdWc2JHOD0=",
+///       "YUdWc2JHOD0=",
+///       "YUdWc2JHOD0=",
+///       "aGVsbG8=",
+///       "aGVsbG8=",
+///       "aGVsbG8=",
+///       "hello",
+///       "hello"
+///     ]
+///   }
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ConditionalJump
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "regex": "[",
+///         "label": "top"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.regex",
+///     "message": {
+///       "en": "Invalid regex: regex parse error:\n    [\n    ^\nerror: unclosed character class",
+///       "ru": "Некорректное регулярное выражение: regex parse error:\n    [\n    ^\nerror: unclosed character class"
+///     }
+///   }
+/// }
+/// ```
+pub struct ConditionalJump;
+
+const NAME: &str = "ConditionalJump";
+const DESCRIPTION_EN: &str = "Goes to the Label with the given name when the data matches the regular expression, or when it doesn't with `invert_condition`. Every jump step is taken at most `max_jumps` times, after that the recipe continues with the next step.";
+const DESCRIPTION_RU: &str = "Переходит к Label с указанным именем, если данные соответствуют регулярному выражению, или если не соответствуют при `invert_condition`. Каждый переход выполняется не более `max_jumps` раз, после этого рецепт продолжается со следующего шага.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    ConditionalJumpInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,9 +1,13 @@
-use crate::{create_me_daddy, utils::SupportedDelimiter, Operation};
-use serde::Deserialize;
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{build_regex, SupportedDelimiter},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for Filter {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
         let (
             input,
             Params {
@@ -11,20 +15,21 @@ impl Operation<'_, DeserializeMeDaddy, String> for Filter {
                 regex,
                 invert_condition,
             },
-        ) = (request.input, request.params);
+        ) = (request.input.into_string()?, request.params);
 
-        let regex = regex::Regex::new(&regex).map_err(|err| err.to_string())?;
+        let regex = build_regex(&regex, false, false, "params.regex")?;
 
-        let output: String = input
-            .split(&delimiter.to_string())
+        let output = input
+            .split(delimiter.as_str())
             .filter(|x| regex.is_match(x) != invert_condition)
-            .collect();
+            .collect::<Vec<_>>()
+            .join(delimiter.as_str());
 
         Ok(output)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     delimiter: SupportedDelimiter,
     regex: String,
@@ -34,3 +39,18 @@ struct Params {
 create_me_daddy!();
 
 pub struct Filter;
+
+const NAME: &str = "Filter";
+const DESCRIPTION_EN: &str = "Splits up the input using the specified delimiter and then filters each branch based on a regular expression.";
+const DESCRIPTION_RU: &str = "Разделяет входные данные с помощью указанного разделителя, а затем фильтрует каждую часть на основе регулярного выражения.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    FilterInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
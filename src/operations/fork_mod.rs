@@ -0,0 +1,194 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    create_info_struct, create_me_daddy, utils::Delimiter, Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, Vec<String>> for Fork {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        if request.params.split_delimiter.as_str().is_empty() {
+            return Err(OperationError::invalid_parameter(
+                "params.split_delimiter",
+                "Split delimiter must not be empty.",
+                "Разделитель не может быть пустым.",
+            ));
+        }
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<Vec<String>, OperationError> {
+        let (input, split_delimiter) =
+            (request.input.into_string()?, request.params.split_delimiter);
+
+        Ok(input
+            .split(split_delimiter.as_str())
+            .map(String::from)
+            .collect())
+    }
+}
+
+/// What a recipe does with a branch whose steps failed.
+#[derive(Deserialize, JsonSchema, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchErrorPolicy {
+    /// The whole recipe fails with the error of the branch.
+    #[default]
+    FailFast,
+    /// The branch is left out of the merged output.
+    Ignore,
+    /// The branch is merged as it was before the fork.
+    KeepOriginal,
+}
+
+/// Parameters of a Fork step as the recipe uses them.
+pub(crate) struct ForkSettings {
+    pub split_delimiter: String,
+    pub merge_delimiter: String,
+    pub on_error: BranchErrorPolicy,
+}
+
+impl Fork {
+    pub(crate) fn settings(&self, params: &Value) -> Result<ForkSettings, OperationError> {
+        let Params {
+            split_delimiter,
+            merge_delimiter,
+            on_error,
+        } = self
+            .validate(&json!({ "input": "", "params": params }).to_string())?
+            .params;
+
+        let split_delimiter = split_delimiter.as_str().to_string();
+        Ok(ForkSettings {
+            merge_delimiter: merge_delimiter
+                .map(|delimiter| delimiter.as_str().to_string())
+                .unwrap_or_else(|| split_delimiter.clone()),
+            split_delimiter,
+            on_error: on_error.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    split_delimiter: Delimiter,
+    merge_delimiter: Option<Delimiter>,
+    on_error: Option<BranchErrorPolicy>,
+}
+
+create_me_daddy!();
+
+/// Splits up the input using the specified delimiter and runs the following steps of a recipe on every branch separately, until a Merge step or the end of the recipe.
+/// <br><br/>
+/// Branches are joined back with the delimiter of Merge, `merge_delimiter` or `split_delimiter`, whichever is set first.
+/// When the operation is sent on its own it returns the list of branches.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Fork with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "split_delimiter": Delimiter,
+///         "merge_delimiter": Option<Delimiter>,
+///         "on_error": Option<BranchErrorPolicy>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Delimiter is one of "Space", "Line feed", "CRLF", "Comma", "Semi-colon", "Colon" or any other non-empty string
+///     - BranchErrorPolicy is enum of "fail_fast", "ignore", "keep_original", "fail_fast" by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Fork
+///
+/// {
+///     "input": "aGk=\nbWFu",
+///     "params": {
+///         "split_delimiter": "Line feed"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": ["aGk=", "bWFu"]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "aGk=\n!!!\nbWFu",
+///     "recipe": [
+///         { "op": "Fork", "params": { "split_delimiter": "Line feed", "on_error": "keep_original" } },
+///         { "op": "FromBase64", "params": { "strict_mode": true } },
+///         { "op": "Merge", "params": { "delimiter": " | " } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "hi | !!! | man",
+///     "steps": [["aGk=", "!!!", "bWFu"], ["hi", "man"], "hi | !!! | man"]
+///   }
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Fork
+///
+/// {
+///     "input": "a,b",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.split_delimiter",
+///     "message": {
+///       "en": "Missing field `params.split_delimiter`.",
+///       "ru": "Отсутствует обязательное поле `params.split_delimiter`."
+///     }
+///   }
+/// }
+/// ```
+pub struct Fork;
+
+const NAME: &str = "Fork";
+const DESCRIPTION_EN: &str = "Splits up the input using the specified delimiter and runs the following steps of a recipe on every branch separately, until a Merge step or the end of the recipe.";
+const DESCRIPTION_RU: &str = "Разделяет входные данные с помощью указанного разделителя и выполняет следующие шаги рецепта для каждой части отдельно, до шага Merge или конца рецепта.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    ForkInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
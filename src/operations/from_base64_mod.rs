@@ -1,16 +1,17 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    create_info_struct, create_me_daddy, libs::base64::from_base64, utils::{DataRepresentation, DataRepresentationInput},
-    Operation, DOCS_URL,
+    create_info_struct, create_me_daddy,
+    libs::base64::from_base64,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
 
-impl Operation<'_, DeserializeMeDaddy, String> for FromBase64 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for FromBase64 {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, alphabet, remove_non_alphabetic_chars, strict_mode) = (
-            request.input,
+            request.input.into_string()?,
             request.params.alphabet,
             request
                 .params
@@ -21,29 +22,33 @@ impl Operation<'_, DeserializeMeDaddy, String> for FromBase64 {
 
         let alphabet = alphabet.unwrap_or_default();
 
-        match from_base64(
+        let DataRepresentation::ByteArray(output) = from_base64(
             input,
             &alphabet,
-            DataRepresentationInput::String,
+            DataRepresentationInput::ByteArray,
             remove_non_alphabetic_chars,
             strict_mode,
-        ) {
-            Ok(output) => {
-                let DataRepresentation::String(output) = output else {
-                    unreachable!()
-                };
-                Ok(output.trim_end_matches('\0').to_string())
-            }
-            Err(e) => Err(e),
+        )?
+        else {
+            unreachable!()
+        };
+
+        match request.params.output_format {
+            Some(format) => format.encode(output, "params.output_format"),
+            None => Ok(DataRepresentation::from_bytes(output)),
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
+    #[schemars(extend("default" = "A-Za-z0-9+/="))]
     alphabet: Option<String>,
+    #[schemars(extend("default" = false))]
     remove_non_alphabetic_chars: Option<bool>,
+    #[schemars(extend("default" = false))]
     strict_mode: Option<bool>,
+    output_format: Option<OutputEncoding>,
 }
 
 create_me_daddy!();
@@ -62,13 +67,15 @@ create_me_daddy!();
 ///     "params": {
 ///         "alphabet": Option<string>,
 ///         "remove_non_alphabetic_chars": Option<bool>,
-///         "strict_mode": Option<bool>
+///         "strict_mode": Option<bool>,
+///         "output_format": Option<OutputEncoding>
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - Option<string> is type that can be string or null.
 ///     - Option<bool> is type that can be bool or null.
+///     - Option<OutputEncoding> encodes the result, see [`OutputEncoding`](crate::OutputEncoding), without it the result is text when it is valid UTF-8 and bytes otherwise.
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -79,7 +86,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -124,9 +131,8 @@ create_me_daddy!();
 /// ``` http
 /// POST /api/FromBase64
 /// content_type: application/json; charset=utf-8
-///
 /// {
-///     "input": ":&5L;&\",
+///     "input": "aGVsbG8=",
 ///     "params": {
 ///         "alphabet": " -_",
 ///         "remove_non_alphabetic_chars": false
@@ -136,7 +142,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `strict_mode`."
+///   "Err": {
+///     "code": "invalid_input_encoding",
+///     "param": "input",
+///     "message": {
+///       "en": "Input string isn't correspond to used base64 alphabet.",
+///       "ru": "Входная строка не соответствует используемому алфавиту base64."
+///     }
+///   }
 /// }
 /// ```
 pub struct FromBase64;
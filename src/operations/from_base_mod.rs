@@ -1,25 +1,41 @@
 use num::{BigInt, Num};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
 
-use crate::{create_info_struct, create_me_daddy, traits::StringTrait, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, traits::StringTrait, Operation, OperationError, DOCS_URL,
+};
 
 impl Operation<'_, DeserializeMeDaddy, String> for FromBase {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, radix) = (request.input, request.params.radix);
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, radix) = (request.input.into_string()?, request.params.radix);
 
         #[allow(non_snake_case)]
-        let big_D_number =
-            BigInt::from_str_radix(&input, radix).map_err(|e| e.to_string().capitalize() + ".")?;
+        let big_D_number = BigInt::from_str_radix(&input, radix).map_err(|err| {
+            OperationError::invalid_input(
+                err.to_string().capitalize() + ".",
+                format!("Входные данные не являются числом в системе счисления {radix}."),
+            )
+        })?;
         Ok(big_D_number.to_string())
     }
 }
 
-#[derive(Deserialize, Validate)]
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
 struct Params {
     #[validate(maximum = 36)]
     #[validate(minimum = 2)]
+    #[schemars(range(min = 2, max = 36))]
     radix: u32,
 }
 
@@ -53,7 +69,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -94,7 +110,6 @@ create_me_daddy!();
 /// ``` http
 /// POST /api/FromBase
 /// content_type: application/json; charset=utf-8
-///
 /// {
 ///     "input": "deadbeef",
 ///     "params": {
@@ -104,7 +119,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `radix`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.radix",
+///     "message": {
+///       "en": "Missing field `params.radix`.",
+///       "ru": "Отсутствует обязательное поле `params.radix`."
+///     }
+///   }
 /// }
 /// ```
 pub struct FromBase;
@@ -0,0 +1,161 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{from_binary, DataRepresentation, SupportedDelimiter},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for FromBinary {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, delimiter, byte_length) = (
+            request.input.into_string()?,
+            request.params.delimiter,
+            request.params.byte_length,
+        );
+
+        let output = from_binary(
+            &input,
+            delimiter.as_ref().map(|delim| delim.as_str()),
+            byte_length,
+        )?;
+
+        match request.params.output_format {
+            Some(format) => format.encode(output, "params.output_format"),
+            None => Ok(DataRepresentation::from_bytes(output)),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    delimiter: Option<SupportedDelimiter>,
+    #[validate(maximum = 8)]
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1, max = 8), extend("default" = 8))]
+    byte_length: Option<usize>,
+    output_format: Option<OutputEncoding>,
+}
+
+create_me_daddy!();
+
+/// Converts a binary string back into its raw form.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Binary_code).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/FromBinary with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "delimiter": Option<Delimiter>,
+///         "byte_length": Option<usize>,
+///         "output_format": Option<OutputEncoding>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Delimiter is one of "Space", "Line feed", "CRLF", "Comma", "Semi-colon", "Colon"
+///     - Option<Delimiter> is type that can be Delimiter or null, null splits on any whitespace.
+///     - Option<usize> is type that can be number from 1 to 8 or null, 8 by default.
+///     - Option<OutputEncoding> encodes the result, see [`OutputEncoding`](crate::OutputEncoding), without it the result is text when it is valid UTF-8 and bytes otherwise.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/FromBinary
+///
+/// {
+///     "input": "01101000 01101001",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hi"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/FromBinary
+///
+/// {
+///     "input": "1101000,1101001",
+///     "params": {
+///         "delimiter": "Comma",
+///         "byte_length": 7
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hi"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/FromBinary
+///
+/// {
+///     "input": "01101000",
+///     "params": {
+///         "byte_length": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.byte_length",
+///     "message": {
+///       "en": "Invalid value of `params.byte_length`: The number must be `>= 1`.",
+///       "ru": "Недопустимое значение параметра `params.byte_length`: The number must be `>= 1`."
+///     }
+///   }
+/// }
+/// ```
+pub struct FromBinary;
+
+const NAME: &str = "FromBinary";
+const DESCRIPTION_EN: &str = "Converts a binary string back into its raw form.";
+const DESCRIPTION_RU: &str = "Преобразует двоичную строку обратно в исходный вид.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Binary_code");
+
+create_info_struct!(
+    FromBinaryInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,135 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{from_hex, DataRepresentation, SupportedDelimiter},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for FromHex {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, delimiter) = (request.input.into_string()?, request.params.delimiter);
+
+        let output = from_hex(&input, delimiter.as_ref().map(|delim| delim.as_str()), None)?;
+
+        match request.params.output_format {
+            Some(format) => format.encode(output, "params.output_format"),
+            None => Ok(DataRepresentation::from_bytes(output)),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    delimiter: Option<SupportedDelimiter>,
+    output_format: Option<OutputEncoding>,
+}
+
+create_me_daddy!();
+
+/// Converts a hexadecimal byte string back into its raw value.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Hexadecimal).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/FromHex with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "delimiter": Option<Delimiter>,
+///         "output_format": Option<OutputEncoding>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Delimiter is one of "Space", "Line feed", "CRLF", "Comma", "Semi-colon", "Colon"
+///     - Option<Delimiter> is type that can be Delimiter or null, null splits on any whitespace.
+///     - Option<OutputEncoding> encodes the result, see [`OutputEncoding`](crate::OutputEncoding), without it the result is text when it is valid UTF-8 and bytes otherwise.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/FromHex
+///
+/// {
+///     "input": "68656c6c6f",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/FromHex
+///
+/// {
+///     "input": "68:65:6c:6c:6f",
+///     "params": {
+///         "delimiter": "Colon"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/FromHex
+///
+/// {
+///     "input": "68 65 6g",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_input_encoding",
+///     "param": "input",
+///     "message": {
+///       "en": "`6g` is not a base 16 byte.",
+///       "ru": "`6g` не является байтом в системе счисления 16."
+///     }
+///   }
+/// }
+/// ```
+pub struct FromHex;
+
+const NAME: &str = "FromHex";
+const DESCRIPTION_EN: &str = "Converts a hexadecimal byte string back into its raw value.";
+const DESCRIPTION_RU: &str =
+    "Преобразует шестнадцатеричную строку байтов обратно в исходное значение.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Hexadecimal");
+
+create_info_struct!(
+    FromHexInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
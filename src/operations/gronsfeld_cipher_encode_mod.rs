@@ -0,0 +1,140 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{alphabet::Language, vigenere_trait::VigenereCipher},
+    utils::add,
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for GronsfeldCipherEncode {}
+
+impl Operation<'_, DeserializeMeDaddy, String> for GronsfeldCipherEncode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+        );
+        <Self as VigenereCipher>::numeric_cipher(&lang, &key, &input, add)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    key: String,
+}
+
+create_me_daddy!();
+
+/// The Gronsfeld cipher is a Vigenere cipher with a key of digits, every digit is the shift of its letter.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Vigenère_cipher#Variants).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GronsfeldCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "key": String
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+///     - key consists of digits from 0 to 9
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GronsfeldCipherEncode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "31415"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Kfpmt, Zpvmi!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GronsfeldCipherEncode
+///
+/// {
+///     "input": "Привет, Мир!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "2718"
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Счйкзщ, Нрт!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/GronsfeldCipherEncode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.key",
+///     "message": {
+///       "en": "Key must consist of digits.",
+///       "ru": "Ключ должен состоять из цифр."
+///     }
+///   }
+/// }
+/// ```
+pub struct GronsfeldCipherEncode;
+
+const NAME: &str = "GronsfeldCipherEncode";
+const DESCRIPTION_EN: &str = "The Gronsfeld cipher is a Vigenere cipher with a key of digits, every digit is the shift of its letter.";
+const DESCRIPTION_RU: &str =
+    "Шифр Гронсфельда — шифр Виженера с ключом из цифр, каждая цифра задаёт сдвиг своей буквы.";
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Vigenère_cipher#Variants");
+
+create_info_struct!(
+    GronsfeldCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
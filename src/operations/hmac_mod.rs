@@ -1,152 +1,60 @@
-use hmac::{Hmac as m_hmac, Mac};
-use md2::*;
-use md4::*;
-use md5::*;
-use ripemd::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sha1::Sha1;
-use sha2::*;
-use whirlpool::*;
 
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::base64::to_base64,
-    traits::StringTrait,
-    utils::{convert_to_byte_array, to_hex, SupportedFormats},
-    Operation, OutputFormat, DOCS_URL,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{convert_to_byte_array, DataRepresentation, DataRepresentationInput, SupportedFormats},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
 
-impl Operation<'_, DeserializeMeDaddy, OutputFormat> for Hmac {
-    fn do_black_magic(&self, request: &str) -> Result<OutputFormat, String> {
-        let request = self.validate(request)?;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Hmac {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, key, key_format, hash_function, output_format) = (
-            request.input,
+            request.input.into_bytes(),
             request.params.key,
             request.params.key_format,
             request.params.hash_function,
             request.params.output_format,
         );
 
-        let key = convert_to_byte_array(&key, &key_format)?;
-        let res: Vec<u8> = match hash_function {
-            SupportedHashFunctions::MD2 => {
-                let mut hasher =
-                    HmacMD2::new_from_slice(&key).map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::MD4 => {
-                let mut hasher =
-                    HmacMD4::new_from_slice(&key).map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::MD5 => {
-                let mut hasher =
-                    HmacMD5::new_from_slice(&key).map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA1 => {
-                let mut hasher =
-                    HmacSha1::new_from_slice(&key).map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA224 => {
-                let mut hasher = HmacSha224::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA256 => {
-                let mut hasher = HmacSha256::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA384 => {
-                let mut hasher = HmacSha384::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512 => {
-                let mut hasher = HmacSha512::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512_224 => {
-                let mut hasher = HmacSha512_224::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512_256 => {
-                let mut hasher = HmacSha512_256::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd128 => {
-                let mut hasher = HmacRipemd128::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd160 => {
-                let mut hasher = HmacRipemd160::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd256 => {
-                let mut hasher = HmacRipemd256::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd320 => {
-                let mut hasher = HmacRipemd320::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::WhirlPool => {
-                let mut hasher = HmacWhirlPool::new_from_slice(&key)
-                    .map_err(|e| e.to_string().capitalize() + ".")?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-        };
+        let key = convert_to_byte_array(&key, &key_format).map_err(|err| {
+            OperationError::invalid_parameter(
+                "params.key",
+                format!("Invalid key: {err}."),
+                format!("Неверный ключ: {err}."),
+            )
+        })?;
+        let mut hasher = Hasher::hmac(
+            match hash_function {
+                SupportedHashFunctions::MD2 => HashAlgorithm::MD2,
+                SupportedHashFunctions::MD4 => HashAlgorithm::MD4,
+                SupportedHashFunctions::MD5 => HashAlgorithm::MD5,
+                SupportedHashFunctions::SHA1 => HashAlgorithm::SHA1,
+                SupportedHashFunctions::SHA224 => HashAlgorithm::SHA224,
+                SupportedHashFunctions::SHA256 => HashAlgorithm::SHA256,
+                SupportedHashFunctions::SHA384 => HashAlgorithm::SHA384,
+                SupportedHashFunctions::SHA512 => HashAlgorithm::SHA512,
+                SupportedHashFunctions::SHA512_224 => HashAlgorithm::SHA512_224,
+                SupportedHashFunctions::SHA512_256 => HashAlgorithm::SHA512_256,
+                SupportedHashFunctions::Ripemd128 => HashAlgorithm::Ripemd128,
+                SupportedHashFunctions::Ripemd160 => HashAlgorithm::Ripemd160,
+                SupportedHashFunctions::Ripemd256 => HashAlgorithm::Ripemd256,
+                SupportedHashFunctions::Ripemd320 => HashAlgorithm::Ripemd320,
+                SupportedHashFunctions::WhirlPool => HashAlgorithm::WhirlPool,
+            },
+            &key,
+        )?;
+        hasher.update(&input);
+        let res = hasher.finalize();
 
-        Ok(match output_format {
-            SupportedOutputFormat::Hex => OutputFormat::Hex(to_hex(&res)),
-            SupportedOutputFormat::Base64 => OutputFormat::Base64(to_base64(&res, None)?),
-            SupportedOutputFormat::Uint8Array => OutputFormat::Uint8Array(res),
-        })
+        output_format.encode_tagged(res, "params.output_format")
     }
 }
 
-type HmacMD2 = m_hmac<Md2>;
-type HmacMD4 = m_hmac<Md4>;
-type HmacMD5 = m_hmac<Md5>;
-type HmacSha1 = m_hmac<Sha1>;
-type HmacSha224 = m_hmac<Sha224>;
-type HmacSha256 = m_hmac<Sha256>;
-type HmacSha384 = m_hmac<Sha384>;
-type HmacSha512 = m_hmac<Sha512>;
-type HmacSha512_224 = m_hmac<Sha512_224>;
-type HmacSha512_256 = m_hmac<Sha512_256>;
-type HmacRipemd128 = m_hmac<Ripemd128>;
-type HmacRipemd160 = m_hmac<Ripemd160>;
-type HmacRipemd256 = m_hmac<Ripemd256>;
-type HmacRipemd320 = m_hmac<Ripemd320>;
-type HmacWhirlPool = m_hmac<Whirlpool>;
-
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 enum SupportedHashFunctions {
     MD2,
@@ -166,20 +74,12 @@ enum SupportedHashFunctions {
     WhirlPool,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedOutputFormat {
-    Hex,
-    Base64,
-    Uint8Array,
-}
-
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     key: String,
     key_format: SupportedFormats,
     hash_function: SupportedHashFunctions,
-    output_format: SupportedOutputFormat,
+    output_format: OutputEncoding,
 }
 
 create_me_daddy!();
@@ -199,14 +99,15 @@ create_me_daddy!();
 ///         "key": string,
 ///         "key_format": SupportedFormats,
 ///         "hash_function": SupportedHashFunctions,
-///         "output_format": SupportedOutputFormat
+///         "output_format": OutputEncoding
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedFormat is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - SupportedFormat is enum of "binary", "utf8", "hex", "base64", "latin1", "decimal"
 ///     - SupportedHashFunctions is enum of "md2", "md4", "md5", "sha1", "sha224", "sha256", "sha384", "sha512", "sha512_224", "sha512_256", "ripemd128", "ripemd160", "ripemd256", "ripemd320", "whirlpool"
-///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
+///     - OutputEncoding is "hex", "base64", "uint8array", "raw" or an object with options, see [`OutputEncoding`](crate::OutputEncoding).
+///       The bare "hex", "base64" and "uint8array" names answer with an object like `{ "hex": ... }`, the rest with the encoded value itself
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -219,9 +120,10 @@ create_me_daddy!();
 ///   }
 /// }
 /// ```
+/// or `{ "Ok": `some answer` }` for the other formats.
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -263,7 +165,7 @@ create_me_daddy!();
 /// ```http
 /// {
 ///   "Ok": {
-///     "base64": "fAzUwzhmrZvCxmTkLc0RbvoNTfORtwk9hDkv2NTvvrU"
+///     "base64": "5guMDPeNMHCxYHJ0qlS42+0qYLFmmSmp8uZvcjiqqrI="
 ///   }
 /// }
 /// ```
@@ -283,7 +185,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `hash_function`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.hash_function",
+///     "message": {
+///       "en": "Missing field `params.hash_function`.",
+///       "ru": "Отсутствует обязательное поле `params.hash_function`."
+///     }
+///   }
 /// }
 /// ```
 pub struct Hmac;
@@ -0,0 +1,168 @@
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Jump {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        Ok(DataRepresentation::from_bytes(request.input.into_bytes()))
+    }
+}
+
+/// How many times one jump step may be taken when `max_jumps` is not set.
+pub(crate) const DEFAULT_MAX_JUMPS: usize = 10;
+
+/// A jump step as the recipe uses it, `condition` is the regex of a ConditionalJump
+/// and whether its result is inverted.
+pub(crate) struct JumpSettings {
+    pub label: String,
+    pub max_jumps: usize,
+    pub condition: Option<(Regex, bool)>,
+}
+
+impl JumpSettings {
+    pub fn should_jump(&self, data: &str) -> bool {
+        match &self.condition {
+            Some((regex, invert)) => regex.is_match(data) != *invert,
+            None => true,
+        }
+    }
+}
+
+impl Jump {
+    pub(crate) fn settings(&self, params: &Value) -> Result<JumpSettings, OperationError> {
+        let Params { label, max_jumps } = self
+            .validate(&json!({ "input": "", "params": params }).to_string())?
+            .params;
+
+        Ok(JumpSettings {
+            label,
+            max_jumps: max_jumps.unwrap_or(DEFAULT_MAX_JUMPS),
+            condition: None,
+        })
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    label: String,
+    #[validate(maximum = 1000)]
+    #[schemars(range(max = 1000), extend("default" = 10))]
+    max_jumps: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Goes back or forward to the Label with the given name. Every jump step is taken at most `max_jumps` times, after that the recipe continues with the next step.
+/// <br><br/>
+/// The data passes through unchanged, the same happens when the operation is sent on its own.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Jump with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "label": string,
+///         "max_jumps": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Option<usize> is type that can be number from 0 to 1000 or null, 10 by default.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "abc",
+///     "recipe": [
+///         { "op": "Label", "params": { "name": "again" } },
+///         { "op": "ToBase64", "params": {} },
+///         { "op": "Jump", "params": { "label": "again", "max_jumps": 2 } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "V1ZkS2FnPT0=",
+///     "steps": ["abc", "YWJj", "YWJj", "YWJj", "WVdKag==", "WVdKag==", "WVdKag==", "V1ZkS2FnPT0=", "V1ZkS2FnPT0="]
+///   }
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "abc",
+///     "recipe": [
+///         { "op": "Jump", "params": { "label": "nowhere" } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.label",
+///     "step": 1,
+///     "message": {
+///       "en": "Step 1 (Jump): Label `nowhere` not found.",
+///       "ru": "Шаг 1 (Jump): Метка `nowhere` не найдена."
+///     }
+///   }
+/// }
+/// ```
+pub struct Jump;
+
+const NAME: &str = "Jump";
+const DESCRIPTION_EN: &str = "Goes back or forward to the Label with the given name. Every jump step is taken at most `max_jumps` times, after that the recipe continues with the next step.";
+const DESCRIPTION_RU: &str = "Переходит назад или вперёд к Label с указанным именем. Каждый переход выполняется не более `max_jumps` раз, после этого рецепт продолжается со следующего шага.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    JumpInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,132 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Label {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        Ok(DataRepresentation::from_bytes(request.input.into_bytes()))
+    }
+}
+
+impl Label {
+    pub(crate) fn name(&self, params: &Value) -> Result<String, OperationError> {
+        Ok(self
+            .validate(&json!({ "input": "", "params": params }).to_string())?
+            .params
+            .name)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    name: String,
+}
+
+create_me_daddy!();
+
+/// Marks a place in a recipe that Jump and ConditionalJump can go to. The data passes through unchanged.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Label with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "name": string
+///     }
+/// }
+/// ```
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "WVVkV2MySkhPRDA9",
+///     "recipe": [
+///         { "op": "Label", "params": { "name": "top" } },
+///         { "op": "FromBase64", "params": {} },
+///         { "op": "ConditionalJump", "params": { "regex": "^(?:[A-Za-z0-9+/]{4})+(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$", "label": "top", "max_jumps": 10 } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "hello",
+///     "steps": [
+///       "WVVkV2MySkhPRDA9",
+///       "YUdWc2JHOD0=",
+///       "YUdWc2JHOD0=",
+///       "YUdWc2JHOD0=",
+///       "aGVsbG8=",
+///       "aGVsbG8=",
+///       "aGVsbG8=",
+///       "hello",
+///       "hello"
+///     ]
+///   }
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Label
+///
+/// {
+///     "input": "hello",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.name",
+///     "message": {
+///       "en": "Missing field `params.name`.",
+///       "ru": "Отсутствует обязательное поле `params.name`."
+///     }
+///   }
+/// }
+/// ```
+pub struct Label;
+
+const NAME: &str = "Label";
+const DESCRIPTION_EN: &str =
+    "Marks a place in a recipe that Jump and ConditionalJump can go to. The data passes through unchanged.";
+const DESCRIPTION_RU: &str = "Отмечает место в рецепте, к которому могут перейти Jump и ConditionalJump. Данные проходят без изменений.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    LabelInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::scoring::{detect_language, entropy, file_signature, printable_ratio},
+    policy,
+    utils::{DataRepresentation, SupportedLanguages},
+    Operation, OperationError, Operations, RecipeStep, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, Vec<MagicResult>> for Magic {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<Vec<MagicResult>, OperationError> {
+        let (input, depth, limit) = (
+            request.input,
+            request.params.depth.unwrap_or(DEFAULT_DEPTH),
+            request.params.limit.unwrap_or(DEFAULT_LIMIT),
+        );
+
+        let decoders = decoders();
+        let mut seen = HashSet::from([input.clone().into_bytes()]);
+        let mut level = vec![(input, Vec::<RecipeStep>::new())];
+        let mut candidates = level.clone();
+
+        for _ in 0..depth {
+            let mut next = Vec::new();
+
+            for (data, recipe) in &level {
+                let Ok(text) = data.clone().into_string() else {
+                    continue;
+                };
+                let text = text.trim();
+
+                for decoder in decoders.iter().filter(|decoder| decoder.matches(text)) {
+                    policy::checkpoint()?;
+                    let Ok(output) = decoder.op.run(
+                        DataRepresentation::String(text.to_string()),
+                        &decoder.params,
+                    ) else {
+                        continue;
+                    };
+
+                    let bytes = output.clone().into_bytes();
+                    if bytes.is_empty() || !seen.insert(bytes) {
+                        continue;
+                    }
+
+                    let mut recipe = recipe.clone();
+                    recipe.push(RecipeStep::new(decoder.op, decoder.params.clone()));
+                    next.push((output, recipe));
+                }
+            }
+
+            candidates.extend(next.iter().cloned());
+            level = next;
+        }
+
+        let mut results: Vec<MagicResult> = candidates
+            .into_iter()
+            .map(|(output, recipe)| MagicResult::new(output, recipe))
+            .filter(MagicResult::is_readable)
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.recipe.len().cmp(&b.recipe.len()))
+        });
+        results.truncate(limit);
+
+        Ok(results)
+    }
+}
+
+const DEFAULT_DEPTH: usize = 3;
+const DEFAULT_LIMIT: usize = 10;
+
+/// Outputs below this share of printable bytes are dropped unless they have a file signature.
+const MIN_PRINTABLE: f64 = 0.9;
+
+/// Operation worth trying when the input looks like `pattern`.
+struct Decoder {
+    op: Operations,
+    params: Value,
+    pattern: Regex,
+}
+
+impl Decoder {
+    fn new(op: Operations, params: Value, pattern: &str) -> Self {
+        Self {
+            op,
+            params,
+            pattern: Regex::new(pattern).unwrap(),
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        self.pattern.is_match(text)
+    }
+}
+
+fn decoders() -> Vec<Decoder> {
+    const HEX: &str = "[0-9a-fA-F]{2}";
+    const A1Z26: &str = "(?:2[0-6]|1[0-9]|[1-9])";
+
+    vec![
+        Decoder::new(
+            Operations::FromBase64,
+            json!({}),
+            r"^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=|[A-Za-z0-9+/]{4})$",
+        ),
+        Decoder::new(
+            Operations::FromBase64,
+            json!({ "alphabet": "A-Za-z0-9-_" }),
+            r"^[A-Za-z0-9_-]*[_-][A-Za-z0-9_-]*$",
+        ),
+        Decoder::new(Operations::FromHex, json!({}), &format!(r"^(?:{HEX})+$")),
+        Decoder::new(
+            Operations::FromHex,
+            json!({}),
+            &format!(r"^{HEX}(?:\s+{HEX})+$"),
+        ),
+        Decoder::new(
+            Operations::FromHex,
+            json!({ "delimiter": "Colon" }),
+            &format!(r"^{HEX}(?::{HEX})+$"),
+        ),
+        Decoder::new(
+            Operations::FromHex,
+            json!({ "delimiter": "Comma" }),
+            &format!(r"^{HEX}(?:,\s*{HEX})+$"),
+        ),
+        Decoder::new(
+            Operations::FromBinary,
+            json!({}),
+            r"^[01]{8}(?:\s*[01]{8})*$",
+        ),
+        Decoder::new(
+            Operations::FromBinary,
+            json!({ "delimiter": "Comma" }),
+            r"^[01]{8}(?:,\s*[01]{8})+$",
+        ),
+        Decoder::new(Operations::FromBase, json!({ "radix": 2 }), r"^[01]+$"),
+        Decoder::new(Operations::FromBase, json!({ "radix": 8 }), r"^[0-7]+$"),
+        Decoder::new(
+            Operations::FromBase,
+            json!({ "radix": 16 }),
+            r"^[0-9a-fA-F]*[a-fA-F][0-9a-fA-F]*$",
+        ),
+        Decoder::new(
+            Operations::A1Z26CipherDecode,
+            json!({ "delimiter": "Space" }),
+            &format!(r"^{A1Z26}(?: {A1Z26})+$"),
+        ),
+        Decoder::new(
+            Operations::A1Z26CipherDecode,
+            json!({ "delimiter": "Comma" }),
+            &format!(r"^{A1Z26}(?:,{A1Z26})+$"),
+        ),
+        Decoder::new(
+            Operations::BaconCipherDecode,
+            bacon_params("A/B"),
+            r"^[AB]{5}(?:\s+[AB]{5})*$",
+        ),
+        Decoder::new(
+            Operations::BaconCipherDecode,
+            bacon_params("0/1"),
+            r"^[01]{5}(?:\s+[01]{5})*$",
+        ),
+    ]
+}
+
+fn bacon_params(translation: &str) -> Value {
+    json!({
+        "bacon_alphabet": "Standard",
+        "translation": translation,
+        "invert_translation": false,
+        "lang": "en"
+    })
+}
+
+#[derive(Serialize)]
+pub struct MagicResult {
+    recipe: Vec<RecipeStep>,
+    output: DataRepresentation,
+    score: f64,
+    printable: f64,
+    entropy: f64,
+    language: Option<SupportedLanguages>,
+    language_score: f64,
+    file_type: Option<&'static str>,
+}
+
+impl MagicResult {
+    fn new(output: DataRepresentation, recipe: Vec<RecipeStep>) -> Self {
+        let bytes = output.clone().into_bytes();
+        let (printable, entropy, file_type) = (
+            printable_ratio(&bytes),
+            entropy(&bytes),
+            file_signature(&bytes),
+        );
+
+        let (language, language_score) =
+            match std::str::from_utf8(&bytes).ok().and_then(detect_language) {
+                Some((language, score)) => (Some(language), score),
+                None => (None, 0.0),
+            };
+
+        let score = match file_type {
+            Some(_) => 1.0,
+            None => printable * (0.3 + 0.7 * language_score),
+        };
+
+        Self {
+            recipe,
+            output,
+            score,
+            printable,
+            entropy,
+            language,
+            language_score,
+            file_type,
+        }
+    }
+
+    fn is_readable(&self) -> bool {
+        self.file_type.is_some() || self.printable >= MIN_PRINTABLE
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    #[validate(maximum = 5)]
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1, max = 5), extend("default" = 3))]
+    depth: Option<usize>,
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1), extend("default" = 10))]
+    limit: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Magic tries to detect how the input was encoded. It speculatively applies FromBase64, FromHex, FromBinary, FromBase, A1Z26CipherDecode and BaconCipherDecode, recursively up to `depth` steps, and ranks every readable output by its printable ratio, entropy, similarity to english or russian text and known file signatures.
+/// <br><br/>
+/// Every result holds the recipe that produced it, it can be sent to /api/recipe as is. The input itself is ranked with an empty recipe.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Magic with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "depth": Option<usize>,
+///         "limit": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - depth is number of decoding steps from 1 to 5, 3 by default.
+///     - limit is maximum number of results, 10 by default.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": [ { "recipe": [ { "op": string, "params": object } ], "output": string, "score": f64, "printable": f64, "entropy": f64, "language": Option<string>, "language_score": f64, "file_type": Option<string> } ] }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Magic
+///
+/// {
+///     "input": "Njg2NTZjNmM2ZjIwNzc2ZjcyNmM2NA==",
+///     "params": {
+///         "depth": 3,
+///         "limit": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": [
+///     {
+///       "recipe": [
+///         { "op": "FromBase64", "params": {} },
+///         { "op": "FromHex", "params": {} }
+///       ],
+///       "output": "hello world",
+///       "score": 0.4824075726141277,
+///       "printable": 1.0,
+///       "entropy": 2.8453509366224368,
+///       "language": "en",
+///       "language_score": 0.260582246591611,
+///       "file_type": null
+///     }
+///   ]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Magic
+///
+/// {
+///     "input": "iVBORw0KGgoAAAANSUhEUg==",
+///     "params": {
+///         "limit": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": [
+///     {
+///       "recipe": [
+///         { "op": "FromBase64", "params": {} }
+///       ],
+///       "output": [137, 80, 78, 71, 13, 10, 26, 10, 0, 0, 0, 13, 73, 72, 68, 82],
+///       "score": 1.0,
+///       "printable": 0.6875,
+///       "entropy": 3.452819531114783,
+///       "language": null,
+///       "language_score": 0.0,
+///       "file_type": "PNG image"
+///     }
+///   ]
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Magic
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "depth": 10
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.depth",
+///     "message": {
+///       "en": "Invalid value of `params.depth`: The number must be `<= 5`.",
+///       "ru": "Недопустимое значение параметра `params.depth`: The number must be `<= 5`."
+///     }
+///   }
+/// }
+/// ```
+pub struct Magic;
+
+const NAME: &str = "Magic";
+const DESCRIPTION_EN: &str = "Magic tries to detect how the input was encoded. It speculatively applies FromBase64, FromHex, FromBinary, FromBase, A1Z26CipherDecode and BaconCipherDecode, recursively up to `depth` steps, and ranks every readable output by its printable ratio, entropy, similarity to english or russian text and known file signatures.";
+const DESCRIPTION_RU: &str = "Magic пытается определить, как были закодированы входные данные. Операция пробует применить FromBase64, FromHex, FromBinary, FromBase, A1Z26CipherDecode и BaconCipherDecode, рекурсивно до `depth` шагов, и ранжирует каждый читаемый результат по доле печатных символов, энтропии, сходству с английским или русским текстом и известным сигнатурам файлов.";
+
+const INFO_URL: Option<&str> = Some("https://github.com/gchq/CyberChef/wiki/Automatic-detection-of-encoded-data-using-CyberChef-Magic");
+
+create_info_struct!(
+    MagicInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,171 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{DataRepresentation, DataRepresentationInput, Delimiter},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Merge {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::Json;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, delimiter) = (request.input.into_json()?, request.params.delimiter);
+
+        let Value::Array(branches) = input else {
+            return Err(OperationError::invalid_input(
+                "Merge expects a list of branches, put a Fork before it.",
+                "Merge ожидает список частей, добавьте перед ним Fork.",
+            ));
+        };
+
+        let delimiter = delimiter.as_ref().map_or("\n", Delimiter::as_str);
+        Ok(join(
+            branches.into_iter().map(DataRepresentation::from).collect(),
+            delimiter,
+        ))
+    }
+}
+
+impl Merge {
+    /// Delimiter of a Merge step, `None` when the Fork decides.
+    pub(crate) fn delimiter(&self, params: &Value) -> Result<Option<String>, OperationError> {
+        Ok(self
+            .validate(&json!({ "input": [], "params": params }).to_string())?
+            .params
+            .delimiter
+            .map(|delimiter| delimiter.as_str().to_string()))
+    }
+}
+
+/// Joins the bytes of every branch, the result is a string when it is valid UTF-8.
+pub(crate) fn join(branches: Vec<DataRepresentation>, delimiter: &str) -> DataRepresentation {
+    let mut output = Vec::new();
+    for (idx, branch) in branches.into_iter().enumerate() {
+        if idx > 0 {
+            output.extend_from_slice(delimiter.as_bytes());
+        }
+        output.extend(branch.into_bytes());
+    }
+    DataRepresentation::from_bytes(output)
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    delimiter: Option<Delimiter>,
+}
+
+create_me_daddy!();
+
+/// Ends the branches started by a Fork and joins their outputs with the delimiter.
+/// <br><br/>
+/// Without a delimiter the `merge_delimiter` of the Fork is used. After a Subsection every part is put back in its place and the delimiter is not used. When the operation is sent on its own it joins a json list, with a line feed by default.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Merge with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": array,
+///     "params": {
+///         "delimiter": Option<Delimiter>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Delimiter is one of "Space", "Line feed", "CRLF", "Comma", "Semi-colon", "Colon" or any other string
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Merge
+///
+/// {
+///     "input": ["hi", "man"],
+///     "params": {
+///         "delimiter": "Comma"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hi,man"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "1 2 3",
+///     "recipe": [
+///         { "op": "Fork", "params": { "split_delimiter": "Space" } },
+///         { "op": "ADD", "params": { "key": "1", "key_format": "utf8" } },
+///         { "op": "Merge", "params": { "delimiter": "-" } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "b-c-d",
+///     "steps": [["1", "2", "3"], ["b", "c", "d"], "b-c-d"]
+///   }
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Merge
+///
+/// {
+///     "input": "hello",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_input_encoding",
+///     "param": "input",
+///     "message": {
+///       "en": "Input is not a valid JSON: expected value at line 1 column 1.",
+///       "ru": "Входные данные не являются корректным JSON: expected value at line 1 column 1."
+///     }
+///   }
+/// }
+/// ```
+pub struct Merge;
+
+const NAME: &str = "Merge";
+const DESCRIPTION_EN: &str =
+    "Ends the branches started by a Fork and joins their outputs with the delimiter.";
+const DESCRIPTION_RU: &str =
+    "Завершает ветви, начатые Fork, и объединяет их результаты через разделитель.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    MergeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -3,6 +3,7 @@ mod a1z26_cipher_encode_mod;
 mod add_line_numbers_mod;
 mod add_mod;
 mod adler32_checksum_mod;
+mod affine_cipher_brute_force_mod;
 mod affine_cipher_decode_mod;
 mod affine_cipher_encode_mod;
 mod analyse_hash_mod;
@@ -10,70 +11,203 @@ mod and_mod;
 mod argon2_compare_mod;
 mod argon2_mod;
 mod atbash_cipher_mod;
+mod autokey_cipher_decode_mod;
+mod autokey_cipher_encode_mod;
 mod bacon_cipher_decode_mod;
 mod bacon_cipher_encode_mod;
 mod bcrypt_compare_mod;
 mod bcrypt_mod;
 mod bcrypt_parse_mod;
+mod beaufort_cipher_decode_mod;
+mod beaufort_cipher_encode_mod;
+mod bifid_cipher_decode_mod;
 mod bifid_cipher_encode_mod;
 mod blake2b_mod;
 mod blake2s_mod;
+mod caesar_cipher_brute_force_mod;
+mod caesar_cipher_decode_mod;
+mod caesar_cipher_encode_mod;
+mod conditional_jump_mod;
 mod filter_mod;
+mod fork_mod;
 mod from_base64_mod;
 mod from_base_mod;
+mod from_binary_mod;
+mod from_hex_mod;
+mod gronsfeld_cipher_decode_mod;
+mod gronsfeld_cipher_encode_mod;
 mod hmac_mod;
+mod jump_mod;
+mod label_mod;
+mod magic_mod;
 mod md2_mod;
 mod md4_mod;
 mod md5_mod;
+mod merge_mod;
+mod porta_cipher_decode_mod;
+mod porta_cipher_encode_mod;
+mod register_mod;
 mod reverse_mod;
+mod rot13_mod;
+mod rot47_mod;
+mod rot8000_mod;
 mod rsa_decrypt_mod;
 mod rsa_encrypt_mod;
 mod sha1_mod;
 mod sha2_mod;
 mod sha3_mod;
+mod subsection_mod;
 mod to_base64_mod;
 mod to_base_mod;
 mod vigenere_cipher_decode_mod;
 mod vigenere_cipher_encode_mod;
+mod vigenere_cipher_key_recovery_mod;
 
 pub use a1z26_cipher_decode_mod::{A1Z26CipherDecode, A1Z26CipherDecodeInfo};
 pub use a1z26_cipher_encode_mod::{A1Z26CipherEncode, A1Z26CipherEncodeInfo};
 pub use add_line_numbers_mod::{AddLineNumbers, AddLineNumbersInfo};
 pub use add_mod::{ADDInfo, ADD};
 pub use adler32_checksum_mod::{Adler32CheckSum, Adler32CheckSumInfo};
+pub use affine_cipher_brute_force_mod::{AffineCipherBruteForce, AffineCipherBruteForceInfo};
 pub use affine_cipher_decode_mod::{AffineCipherDecode, AffineCipherDecodeInfo};
 pub use affine_cipher_encode_mod::{AffineCipherEncode, AffineCipherEncodeInfo};
 pub use analyse_hash_mod::{AnalyseHash, AnalyseHashInfo};
-pub use and_mod::{AND, ANDInfo};
+pub use and_mod::{ANDInfo, AND};
 pub use argon2_compare_mod::{Argon2Compare, Argon2CompareInfo};
 pub use argon2_mod::{Argon2, Argon2Info};
 pub use atbash_cipher_mod::{AtbashCipher, AtbashCipherInfo};
+pub use autokey_cipher_decode_mod::{AutokeyCipherDecode, AutokeyCipherDecodeInfo};
+pub use autokey_cipher_encode_mod::{AutokeyCipherEncode, AutokeyCipherEncodeInfo};
 pub use bacon_cipher_decode_mod::{BaconCipherDecode, BaconCipherDecodeInfo};
 pub use bacon_cipher_encode_mod::{BaconCipherEncode, BaconCipherEncodeInfo};
 pub use bcrypt_compare_mod::{BcryptCompare, BcryptCompareInfo};
 pub use bcrypt_mod::{Bcrypt, BcryptInfo};
 pub use bcrypt_parse_mod::{BcryptParse, BcryptParseInfo};
+pub use beaufort_cipher_decode_mod::{BeaufortCipherDecode, BeaufortCipherDecodeInfo};
+pub use beaufort_cipher_encode_mod::{BeaufortCipherEncode, BeaufortCipherEncodeInfo};
+pub use bifid_cipher_decode_mod::{BifidCipherDecode, BifidCipherDecodeInfo};
 pub use bifid_cipher_encode_mod::{BifidCipherEncode, BifidCipherEncodeInfo};
 pub use blake2b_mod::{Blake2b, Blake2bInfo};
 pub use blake2s_mod::{Blake2s, Blake2sInfo};
-pub use filter_mod::Filter;
+pub use caesar_cipher_brute_force_mod::{CaesarCipherBruteForce, CaesarCipherBruteForceInfo};
+pub use caesar_cipher_decode_mod::{CaesarCipherDecode, CaesarCipherDecodeInfo};
+pub use caesar_cipher_encode_mod::{CaesarCipherEncode, CaesarCipherEncodeInfo};
+pub use conditional_jump_mod::{ConditionalJump, ConditionalJumpInfo};
+pub use filter_mod::{Filter, FilterInfo};
+pub use fork_mod::{Fork, ForkInfo};
 pub use from_base64_mod::{FromBase64, FromBase64Info};
 pub use from_base_mod::{FromBase, FromBaseInfo};
+pub use from_binary_mod::{FromBinary, FromBinaryInfo};
+pub use from_hex_mod::{FromHex, FromHexInfo};
+pub use gronsfeld_cipher_decode_mod::{GronsfeldCipherDecode, GronsfeldCipherDecodeInfo};
+pub use gronsfeld_cipher_encode_mod::{GronsfeldCipherEncode, GronsfeldCipherEncodeInfo};
 pub use hmac_mod::{Hmac, HmacInfo};
+pub use jump_mod::{Jump, JumpInfo};
+pub use label_mod::{Label, LabelInfo};
+pub use magic_mod::{Magic, MagicInfo};
 pub use md2_mod::{Md2Info, MD2};
 pub use md4_mod::{Md4Info, MD4};
 pub use md5_mod::{Md5Info, MD5};
-pub use reverse_mod::ReverseString;
+pub use merge_mod::{Merge, MergeInfo};
+pub use porta_cipher_decode_mod::{PortaCipherDecode, PortaCipherDecodeInfo};
+pub use porta_cipher_encode_mod::{PortaCipherEncode, PortaCipherEncodeInfo};
+pub use register_mod::{Register, RegisterInfo};
+pub use reverse_mod::{ReverseString, ReverseStringInfo};
+pub use rot13_mod::{ROT13Info, ROT13};
+pub use rot47_mod::{ROT47Info, ROT47};
+pub use rot8000_mod::{ROT8000Info, ROT8000};
 pub use rsa_decrypt_mod::{RSADecrypt, RSADecryptInfo};
 pub use rsa_encrypt_mod::{RSAEncrypt, RSAEncryptInfo};
 pub use sha1_mod::{Sha1Info, SHA1};
 pub use sha2_mod::{Sha2Info, SHA2};
 pub use sha3_mod::{Sha3Info, SHA3};
+pub use subsection_mod::{Subsection, SubsectionInfo};
 pub use to_base64_mod::{ToBase64, ToBase64Info};
 pub use to_base_mod::{ToBase, ToBaseInfo};
 pub use vigenere_cipher_decode_mod::{VigenereCipherDecode, VigenereCipherDecodeInfo};
 pub use vigenere_cipher_encode_mod::{VigenereCipherEncode, VigenereCipherEncodeInfo};
+pub use vigenere_cipher_key_recovery_mod::{
+    VigenereCipherKeyRecovery, VigenereCipherKeyRecoveryInfo,
+};
 
 pub use a1z26_cipher_decode_mod::Delimiters;
+pub use affine_cipher_brute_force_mod::AffineKey;
 pub use analyse_hash_mod::SerializeMeDaddy as AnalyseHashSerializeMeDaddy;
 pub use bcrypt_parse_mod::HashParts as BcryptParseHashParts;
+pub use caesar_cipher_brute_force_mod::CaesarKey;
+pub use fork_mod::BranchErrorPolicy;
+pub use magic_mod::MagicResult;
+pub use vigenere_cipher_key_recovery_mod::VigenereKey;
+
+pub(crate) use merge_mod::join as merge_branches;
+pub(crate) use register_mod::capture;
+pub(crate) use subsection_mod::restore as restore_sections;
+
+use crate::create_operations_registry;
+
+create_operations_registry!(
+    A1Z26CipherDecode => A1Z26CipherDecode, A1Z26CipherDecodeInfo, EncryptionEncoding;
+    A1Z26CipherEncode => A1Z26CipherEncode, A1Z26CipherEncodeInfo, EncryptionEncoding;
+    ADD => ADD, ADDInfo, ArithmeticLogic;
+    AddLineNumbers => AddLineNumbers, AddLineNumbersInfo, Utils;
+    Adler32CheckSum => Adler32CheckSum, Adler32CheckSumInfo, Hashing;
+    AffineCipherBruteForce => AffineCipherBruteForce, AffineCipherBruteForceInfo, EncryptionEncoding;
+    AffineCipherDecode => AffineCipherDecode, AffineCipherDecodeInfo, EncryptionEncoding;
+    AffineCipherEncode => AffineCipherEncode, AffineCipherEncodeInfo, EncryptionEncoding;
+    AnalyseHash => AnalyseHash, AnalyseHashInfo, Hashing;
+    AND => AND, ANDInfo, ArithmeticLogic;
+    Argon2Compare => Argon2Compare, Argon2CompareInfo, Hashing;
+    Argon2 => Argon2, Argon2Info, Hashing;
+    AtbashCipher => AtbashCipher, AtbashCipherInfo, EncryptionEncoding;
+    AutokeyCipherDecode => AutokeyCipherDecode, AutokeyCipherDecodeInfo, EncryptionEncoding;
+    AutokeyCipherEncode => AutokeyCipherEncode, AutokeyCipherEncodeInfo, EncryptionEncoding;
+    BaconCipherEncode => BaconCipherEncode, BaconCipherEncodeInfo, EncryptionEncoding;
+    BaconCipherDecode => BaconCipherDecode, BaconCipherDecodeInfo, EncryptionEncoding;
+    BcryptCompare => BcryptCompare, BcryptCompareInfo, Hashing;
+    Bcrypt => Bcrypt, BcryptInfo, Hashing;
+    BcryptParse => BcryptParse, BcryptParseInfo, Hashing;
+    BeaufortCipherDecode => BeaufortCipherDecode, BeaufortCipherDecodeInfo, EncryptionEncoding;
+    BeaufortCipherEncode => BeaufortCipherEncode, BeaufortCipherEncodeInfo, EncryptionEncoding;
+    BifidCipherDecode => BifidCipherDecode, BifidCipherDecodeInfo, EncryptionEncoding;
+    BifidCipherEncode => BifidCipherEncode, BifidCipherEncodeInfo, EncryptionEncoding;
+    Blake2b => Blake2b, Blake2bInfo, Hashing;
+    Blake2s => Blake2s, Blake2sInfo, Hashing;
+    CaesarCipherBruteForce => CaesarCipherBruteForce, CaesarCipherBruteForceInfo, EncryptionEncoding;
+    CaesarCipherDecode => CaesarCipherDecode, CaesarCipherDecodeInfo, EncryptionEncoding;
+    CaesarCipherEncode => CaesarCipherEncode, CaesarCipherEncodeInfo, EncryptionEncoding;
+    ConditionalJump => ConditionalJump, ConditionalJumpInfo, FlowControl;
+    Filter => Filter, FilterInfo, Utils;
+    Fork => Fork, ForkInfo, FlowControl;
+    FromBase64 => FromBase64, FromBase64Info, DataFormat;
+    FromBase => FromBase, FromBaseInfo, DataFormat;
+    FromBinary => FromBinary, FromBinaryInfo, DataFormat;
+    FromHex => FromHex, FromHexInfo, DataFormat;
+    GronsfeldCipherDecode => GronsfeldCipherDecode, GronsfeldCipherDecodeInfo, EncryptionEncoding;
+    GronsfeldCipherEncode => GronsfeldCipherEncode, GronsfeldCipherEncodeInfo, EncryptionEncoding;
+    HMAC => Hmac, HmacInfo, Hashing;
+    Jump => Jump, JumpInfo, FlowControl;
+    Label => Label, LabelInfo, FlowControl;
+    Magic => Magic, MagicInfo, DataFormat;
+    MD2 => MD2, Md2Info, Hashing;
+    MD4 => MD4, Md4Info, Hashing;
+    MD5 => MD5, Md5Info, Hashing;
+    Merge => Merge, MergeInfo, FlowControl;
+    PortaCipherDecode => PortaCipherDecode, PortaCipherDecodeInfo, EncryptionEncoding;
+    PortaCipherEncode => PortaCipherEncode, PortaCipherEncodeInfo, EncryptionEncoding;
+    Register => Register, RegisterInfo, FlowControl;
+    ReverseString => ReverseString, ReverseStringInfo, Utils;
+    ROT13 => ROT13, ROT13Info, EncryptionEncoding;
+    ROT47 => ROT47, ROT47Info, EncryptionEncoding;
+    ROT8000 => ROT8000, ROT8000Info, EncryptionEncoding;
+    RSADecrypt => RSADecrypt, RSADecryptInfo, EncryptionEncoding;
+    RSAEncrypt => RSAEncrypt, RSAEncryptInfo, EncryptionEncoding;
+    SHA1 => SHA1, Sha1Info, Hashing;
+    SHA2 => SHA2, Sha2Info, Hashing;
+    SHA3 => SHA3, Sha3Info, Hashing;
+    Subsection => Subsection, SubsectionInfo, FlowControl;
+    ToBase64 => ToBase64, ToBase64Info, DataFormat;
+    ToBase => ToBase, ToBaseInfo, DataFormat;
+    VigenereCipherDecode => VigenereCipherDecode, VigenereCipherDecodeInfo, EncryptionEncoding;
+    VigenereCipherEncode => VigenereCipherEncode, VigenereCipherEncodeInfo, EncryptionEncoding;
+    VigenereCipherKeyRecovery => VigenereCipherKeyRecovery, VigenereCipherKeyRecoveryInfo, EncryptionEncoding;
+);
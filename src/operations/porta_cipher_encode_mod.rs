@@ -0,0 +1,144 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{
+        alphabet::Language,
+        ciphers::{porta, porta_half},
+        vigenere_trait::VigenereCipher,
+    },
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for PortaCipherEncode {}
+
+impl Operation<'_, DeserializeMeDaddy, String> for PortaCipherEncode {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+        );
+        let half = porta_half(&lang.alphabet()?)?;
+
+        <Self as VigenereCipher>::cipher(&lang, &key, &input, |text, key| porta(text, key, half))
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    lang: Language,
+    key: String,
+}
+
+create_me_daddy!();
+
+/// The Porta cipher is a reciprocal polyalphabetic cipher, every pair of key letters swaps the letters of one half of the alphabet with the letters of the other half, so decoding is the same as encoding.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Giambattista_della_Porta).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PortaCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "key": String
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
+///     - the alphabet has to be of even length, "ru_with_yo" and "uk" are not
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PortaCipherEncode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Ztxqm, Kjcxv!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PortaCipherEncode
+///
+/// {
+///     "input": "Привет, Мир!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Флчэън, Ыул!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PortaCipherEncode
+///
+/// {
+///     "input": "Привіт, світе!",
+///     "params": {
+///         "lang": "uk",
+///         "key": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Porta cipher needs an alphabet of even length, not 33.",
+///       "ru": "Шифру Порта нужен алфавит чётной длины, а не 33."
+///     }
+///   }
+/// }
+/// ```
+pub struct PortaCipherEncode;
+
+const NAME: &str = "PortaCipherEncode";
+const DESCRIPTION_EN: &str = "The Porta cipher is a reciprocal polyalphabetic cipher, every pair of key letters swaps the letters of one half of the alphabet with the letters of the other half, so decoding is the same as encoding.";
+const DESCRIPTION_RU: &str = "Шифр Порта — обратимый полиалфавитный шифр, каждая пара букв ключа меняет местами буквы одной половины алфавита с буквами другой, поэтому расшифрование совпадает с шифрованием.";
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Giambattista_della_Porta");
+
+create_info_struct!(
+    PortaCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,161 @@
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{build_regex, DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for Register {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        request.params.extractor()?;
+
+        Ok(DataRepresentation::from_bytes(request.input.into_bytes()))
+    }
+}
+
+impl Register {
+    pub(crate) fn extractor(&self, params: &Value) -> Result<Regex, OperationError> {
+        self.validate(&json!({ "input": "", "params": params }).to_string())?
+            .params
+            .extractor()
+    }
+}
+
+/// Values captured from `input`, the whole match when the regex has no groups.
+pub(crate) fn capture(extractor: &Regex, input: &str) -> Vec<String> {
+    let Some(captures) = extractor.captures(input) else {
+        return Vec::new();
+    };
+
+    match captures.len() {
+        1 => vec![captures[0].to_string()],
+        _ => captures
+            .iter()
+            .skip(1)
+            .map(|group| group.map_or_else(String::new, |group| group.as_str().to_string()))
+            .collect(),
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    extractor: String,
+    case_insensitive: Option<bool>,
+    multiline: Option<bool>,
+}
+
+impl Params {
+    fn extractor(&self) -> Result<Regex, OperationError> {
+        build_regex(
+            &self.extractor,
+            self.case_insensitive.unwrap_or_default(),
+            self.multiline.unwrap_or_default(),
+            "params.extractor",
+        )
+    }
+}
+
+create_me_daddy!();
+
+/// Extracts data from the input with a regular expression and stores every capture group in a register of the recipe: `$R0`, `$R1` and so on, numbering continues over all Register steps.
+/// <br><br/>
+/// Every string in the params of the following steps has its registers replaced with the captured values, so a key found in the input can be used by HMAC or VigenereCipherDecode. The data passes through unchanged, the same happens when the operation is sent on its own.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Register with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "extractor": string,
+///         "case_insensitive": Option<bool>,
+///         "multiline": Option<bool>
+///     }
+/// }
+/// ```
+/// #### where
+///     - extractor is a regular expression, without capture groups the whole match is stored.
+///     - Option<bool> is type that can be bool or null, false by default.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "key=lemon\nlxfopvefrnhr",
+///     "recipe": [
+///         { "op": "Register", "params": { "extractor": "key=(\\w+)\\n" } },
+///         { "op": "Filter", "params": { "delimiter": "Line feed", "regex": "=", "invert_condition": true } },
+///         { "op": "VigenereCipherDecode", "params": { "key": "$R0", "lang": "en" } }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "attackatdawn",
+///     "steps": ["key=lemon\nlxfopvefrnhr", "lxfopvefrnhr", "attackatdawn"]
+///   }
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Register
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "extractor": "(h"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.extractor",
+///     "message": {
+///       "en": "Invalid regex: regex parse error:\n    (h\n    ^\nerror: unclosed group",
+///       "ru": "Некорректное регулярное выражение: regex parse error:\n    (h\n    ^\nerror: unclosed group"
+///     }
+///   }
+/// }
+/// ```
+pub struct Register;
+
+const NAME: &str = "Register";
+const DESCRIPTION_EN: &str = "Extracts data from the input with a regular expression and stores every capture group in a register of the recipe: `$R0`, `$R1` and so on, numbering continues over all Register steps.";
+const DESCRIPTION_RU: &str = "Извлекает данные из входных данных с помощью регулярного выражения и сохраняет каждую группу захвата в регистр рецепта: `$R0`, `$R1` и так далее, нумерация продолжается во всех шагах Register.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    RegisterInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
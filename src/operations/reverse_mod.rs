@@ -1,17 +1,39 @@
-use crate::Operation;
-use serde::Deserialize;
+use crate::{
+    create_info_struct, impl_operation_request,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 impl Operation<'_, DeserializeMeDaddy, String> for ReverseString {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let input = request.input;
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let input = request.input.into_string()?;
         Ok(input.chars().rev().collect())
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    input: String,
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
 }
 
+impl_operation_request!();
+
 pub struct ReverseString;
+
+const NAME: &str = "Reverse";
+const DESCRIPTION_EN: &str = "Reverses the input string.";
+const DESCRIPTION_RU: &str = "Переворачивает входную строку.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    ReverseStringInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,155 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, impl_operation_request,
+    libs::ciphers::rot13,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, String> for ROT13 {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, params) = (
+            request.input.into_string()?,
+            request.params.unwrap_or_default(),
+        );
+
+        Ok(rot13(
+            &input,
+            params.amount.unwrap_or(13),
+            params.rotate_lower.unwrap_or(true),
+            params.rotate_upper.unwrap_or(true),
+            params.rotate_numbers.unwrap_or(false),
+        ))
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Default)]
+struct Params {
+    #[schemars(extend("default" = 13))]
+    amount: Option<i64>,
+    #[schemars(extend("default" = true))]
+    rotate_lower: Option<bool>,
+    #[schemars(extend("default" = true))]
+    rotate_upper: Option<bool>,
+    #[schemars(extend("default" = false))]
+    rotate_numbers: Option<bool>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+pub struct DeserializeMeDaddy {
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+    params: Option<Params>,
+}
+
+impl_operation_request!();
+
+/// A simple caesar substitution cipher which rotates alphabet characters by the specified amount (default 13).
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/ROT13)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT13 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "amount": Option<i64>,
+///         "rotate_lower": Option<bool>,
+///         "rotate_upper": Option<bool>,
+///         "rotate_numbers": Option<bool>
+///     }
+/// }
+/// ```
+/// #### where
+///     - params may be left out
+///     - amount is number of positions to rotate by, 13 by default, letters wrap around 26 and digits around 10
+///     - rotate_lower and rotate_upper are `true` by default, rotate_numbers is `false` by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Hello, World!"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Uryyb, Jbeyq!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Agent 007",
+///     "params": {
+///         "amount": 5,
+///         "rotate_numbers": true
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Fljsy 552"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "amount": 1.5
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.amount",
+///     "message": {
+///       "en": "Invalid value of `params.amount`: invalid type: floating point `1.5`, expected i64.",
+///       "ru": "Недопустимое значение параметра `params.amount`: invalid type: floating point `1.5`, expected i64."
+///     }
+///   }
+/// }
+/// ```
+pub struct ROT13;
+
+const NAME: &str = "ROT13";
+const DESCRIPTION_EN: &str = "A simple caesar substitution cipher which rotates alphabet characters by the specified amount (default 13).";
+const DESCRIPTION_RU: &str = "Простой шифр замены Цезаря, сдвигающий символы алфавита на указанное количество позиций (по умолчанию 13).";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/ROT13");
+
+create_info_struct!(
+    ROT13Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
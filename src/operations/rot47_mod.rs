@@ -0,0 +1,141 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, impl_operation_request,
+    libs::ciphers::rot47,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, String> for ROT47 {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, amount) = (
+            request.input.into_string()?,
+            request
+                .params
+                .and_then(|params| params.amount)
+                .unwrap_or(47),
+        );
+
+        Ok(rot47(&input, amount))
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    #[schemars(extend("default" = 47))]
+    amount: Option<i64>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+pub struct DeserializeMeDaddy {
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+    params: Option<Params>,
+}
+
+impl_operation_request!();
+
+/// A slightly more complex variation of a caesar cipher, which includes ASCII characters from 33 '!' to 126 '~'. Default rotation: 47.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/ROT13#Variants)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT47 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "amount": Option<i64>
+///     }
+/// }
+/// ```
+/// #### where
+///     - params may be left out
+///     - amount is number of positions to rotate by, 47 by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "Hello, World!"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "w6==@[ (@C=5P"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "amount": -1
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Gdkkn+ Vnqkc~"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "amount": "47"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.amount",
+///     "message": {
+///       "en": "Invalid value of `params.amount`: invalid type: string \"47\", expected i64.",
+///       "ru": "Недопустимое значение параметра `params.amount`: invalid type: string \"47\", expected i64."
+///     }
+///   }
+/// }
+/// ```
+pub struct ROT47;
+
+const NAME: &str = "ROT47";
+const DESCRIPTION_EN: &str = "A slightly more complex variation of a caesar cipher, which includes ASCII characters from 33 '!' to 126 '~'. Default rotation: 47.";
+const DESCRIPTION_RU: &str = "Немного более сложная вариация шифра Цезаря, включающая символы ASCII от 33 '!' до 126 '~'. Сдвиг по умолчанию: 47.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/ROT13#Variants");
+
+create_info_struct!(
+    ROT47Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
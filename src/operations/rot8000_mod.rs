@@ -0,0 +1,119 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct, impl_operation_request,
+    libs::ciphers::rot8000,
+    utils::{DataRepresentation, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, String> for ROT8000 {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let input = request.input.into_string()?;
+
+        Ok(rot8000(&input))
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+pub struct DeserializeMeDaddy {
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+}
+
+impl_operation_request!();
+
+/// The simple Caesar-cypher encryption that replaces each Unicode character with the one 0x8000 places forward or back along the alphabet.
+/// <br><br/>
+/// For more information go [here](https://rot8000.com/info)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT8000 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string
+/// }
+/// ```
+/// #### where
+///     - whitespace, control characters and characters out of the Basic Multilingual Plane are kept as is
+///     - the rotation is its own inverse, encoding twice gives back the input
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+///     "input": "Hello, World!"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "籑籮籵籵籸簵 籠籸类籵籭簪"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+///     "input": "籑籮籵籵籸簵 籠籸类籵籭簪"
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+///     "text": "Hello, World!"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "input",
+///     "message": {
+///       "en": "Missing field `input`.",
+///       "ru": "Отсутствует обязательное поле `input`."
+///     }
+///   }
+/// }
+/// ```
+pub struct ROT8000;
+
+const NAME: &str = "ROT8000";
+const DESCRIPTION_EN: &str = "The simple Caesar-cypher encryption that replaces each Unicode character with the one 0x8000 places forward or back along the alphabet.";
+const DESCRIPTION_RU: &str = "Простое шифрование Цезаря, заменяющее каждый символ Unicode символом, отстоящим на 0x8000 позиций вперёд или назад по алфавиту.";
+
+const INFO_URL: Option<&str> = Some("https://rot8000.com/info");
+
+create_info_struct!(
+    ROT8000Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,4 +1,5 @@
 use rsa::{pkcs1::DecodeRsaPrivateKey, Oaep, Pkcs1v15Encrypt, RsaPrivateKey};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512};
@@ -6,16 +7,15 @@ use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::base64::{from_base64, to_base64},
-    utils::{to_hex, DataRepresentation, DataRepresentationInput},
-    Operation, OutputFormat, DOCS_URL,
+    libs::base64::from_base64,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
 
-impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSADecrypt {
-    fn do_black_magic(&self, request: &str) -> Result<OutputFormat, String> {
-        let request = self.validate(request)?;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for RSADecrypt {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, pem_key, encrypted_scheme, message_digest_algorithm, output_format) = (
-            request.input,
+            request.input.into_string()?,
             request.params.private_key,
             request.params.encrypted_scheme,
             request.params.message_digest_algorithm,
@@ -25,22 +25,25 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSADecrypt {
         if matches!(encrypted_scheme, SupportedEncryptionSchemes::RSA_OAEP)
             && message_digest_algorithm.is_none()
         {
-            return Err("RSA_OAEP must have message digest algorithm".to_string());
+            return Err(OperationError::MissingField {
+                param: "params.message_digest_algorithm".to_string(),
+            });
         }
 
-        let DataRepresentation::ByteArray(input) = from_base64(
-            input,
-            "",
-            DataRepresentationInput::ByteArray,
-            false,
-            false,
-        )?
+        let DataRepresentation::ByteArray(input) =
+            from_base64(input, "", DataRepresentationInput::ByteArray, false, false)?
         else {
             unreachable!()
         };
 
         let pem_key: RsaPrivateKey =
-            DecodeRsaPrivateKey::from_pkcs1_pem(&pem_key).map_err(|err| err.to_string())?;
+            DecodeRsaPrivateKey::from_pkcs1_pem(&pem_key).map_err(|err| {
+                OperationError::invalid_parameter(
+                    "params.private_key",
+                    format!("Invalid private key: {err}."),
+                    format!("Некорректный закрытый ключ: {err}."),
+                )
+            })?;
 
         let encrypted_text = match encrypted_scheme {
             SupportedEncryptionSchemes::RSA_OAEP => {
@@ -61,20 +64,14 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSADecrypt {
                 pem_key.decrypt(Pkcs1v15Encrypt, &input)
             }
         }
-        .map_err(|err| err.to_string())?;
+        .map_err(OperationError::crypto_failure)?;
 
-        Ok(match output_format {
-            SupportedOutputFormat::Hex => OutputFormat::Hex(to_hex(&encrypted_text)),
-            SupportedOutputFormat::Base64 => {
-                OutputFormat::Base64(to_base64(&encrypted_text, None)?)
-            }
-            SupportedOutputFormat::Uint8Array => OutputFormat::Uint8Array(encrypted_text),
-        })
+        output_format.encode_tagged(encrypted_text, "params.output_format")
     }
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 enum SupportedEncryptionSchemes {
     #[serde(rename = "oaep")]
     RSA_OAEP,
@@ -83,7 +80,7 @@ enum SupportedEncryptionSchemes {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 enum SupportedMessageDigestAlgorithm {
     SHA1,
@@ -97,15 +94,7 @@ enum SupportedMessageDigestAlgorithm {
     SHA3_512,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedOutputFormat {
-    Hex,
-    Base64,
-    Uint8Array,
-}
-
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     #[serde(rename = "pem_key")]
     private_key: String,
@@ -113,7 +102,7 @@ struct Params {
     encrypted_scheme: SupportedEncryptionSchemes,
     #[serde(rename = "digest_alg")]
     message_digest_algorithm: Option<SupportedMessageDigestAlgorithm>,
-    output_format: SupportedOutputFormat,
+    output_format: OutputEncoding,
 }
 
 create_me_daddy!();
@@ -133,7 +122,7 @@ create_me_daddy!();
 ///         "pem_key": PEM,
 ///         "scheme": SupportedEncryptionSchemes,
 ///         "digest_alg": Option<SupportedMessageDigestAlgorithm>
-///         "output_format": SupportedOutputFormat
+///         "output_format": OutputEncoding
 ///     }
 /// }
 /// ```
@@ -142,7 +131,8 @@ create_me_daddy!();
 ///     - PEM is pem encoded RSA public key
 ///     - SupportedEncryptionSchemes is enum of "oaep" and "pkcs1_v15"
 ///     - Option<SupportedMessageDigestAlgorithm> is optional enum of "sha1", "sha2-224", "sha2-256", "sha2-384", "sha2-512", "sha3-224", "sha3-256", "sha3-384", "sha3-512"
-///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
+///     - OutputEncoding is "hex", "base64", "uint8array", "raw" or an object with options, see [`OutputEncoding`](crate::OutputEncoding).
+///       The bare "hex", "base64" and "uint8array" names answer with an object like `{ "hex": ... }`, the rest with the encoded value itself
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -155,9 +145,10 @@ create_me_daddy!();
 ///   }
 /// }
 /// ```
+/// or `{ "Ok": `some answer` }` for the other formats.
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -217,7 +208,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `pem_key`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.pem_key",
+///     "message": {
+///       "en": "Missing field `params.pem_key`.",
+///       "ru": "Отсутствует обязательное поле `params.pem_key`."
+///     }
+///   }
 /// }
 /// ```
 pub struct RSADecrypt;
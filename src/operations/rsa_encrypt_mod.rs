@@ -1,19 +1,22 @@
 use rsa::{pkcs1::DecodeRsaPublicKey, Oaep, Pkcs1v15Encrypt, RsaPublicKey};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512};
 use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
 use crate::{
-    create_info_struct, create_me_daddy, libs::base64::to_base64, utils::to_hex, Operation,
-    OutputFormat, DOCS_URL,
+    create_info_struct, create_me_daddy,
+    utils::{DataRepresentation, DataRepresentationInput},
+    Operation, OperationError, OutputEncoding, DOCS_URL,
 };
 
-impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSAEncrypt {
-    fn do_black_magic(&self, request: &str) -> Result<OutputFormat, String> {
-        let request = self.validate(request)?;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for RSAEncrypt {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
         let (input, public_key, encrypted_scheme, message_digest_algorithm, output_format) = (
-            request.input,
+            request.input.into_bytes(),
             request.params.public_key,
             request.params.encrypted_scheme,
             request.params.message_digest_algorithm,
@@ -23,10 +26,18 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSAEncrypt {
         if matches!(encrypted_scheme, SupportedEncryptionSchemes::RSA_OAEP)
             && message_digest_algorithm.is_none()
         {
-            return Err("RSA_OAEP must have message digest algorithm".to_string());
+            return Err(OperationError::MissingField {
+                param: "params.message_digest_algorithm".to_string(),
+            });
         }
 
-        let pub_key = RsaPublicKey::from_pkcs1_pem(&public_key).map_err(|err| err.to_string())?;
+        let pub_key = RsaPublicKey::from_pkcs1_pem(&public_key).map_err(|err| {
+            OperationError::invalid_parameter(
+                "params.public_key",
+                format!("Invalid public key: {err}."),
+                format!("Некорректный открытый ключ: {err}."),
+            )
+        })?;
         let mut rng = rand::thread_rng();
 
         let encrypted_text = match encrypted_scheme {
@@ -42,25 +53,19 @@ impl Operation<'_, DeserializeMeDaddy, OutputFormat> for RSAEncrypt {
                     SupportedMessageDigestAlgorithm::SHA3_384 => Oaep::new::<Sha3_384>(),
                     SupportedMessageDigestAlgorithm::SHA3_512 => Oaep::new::<Sha3_512>(),
                 };
-                pub_key.encrypt(&mut rng, padding, input.as_bytes())
+                pub_key.encrypt(&mut rng, padding, &input)
             }
             SupportedEncryptionSchemes::RSA_AES_PKCS1_V1_5 => {
-                pub_key.encrypt(&mut rng, Pkcs1v15Encrypt, input.as_bytes())
+                pub_key.encrypt(&mut rng, Pkcs1v15Encrypt, &input)
             }
         }
-        .map_err(|err| err.to_string())?;
-        Ok(match output_format {
-            SupportedOutputFormat::Hex => OutputFormat::Hex(to_hex(&encrypted_text)),
-            SupportedOutputFormat::Base64 => {
-                OutputFormat::Base64(to_base64(&encrypted_text, None)?)
-            }
-            SupportedOutputFormat::Uint8Array => OutputFormat::Uint8Array(encrypted_text),
-        })
+        .map_err(OperationError::crypto_failure)?;
+        output_format.encode_tagged(encrypted_text, "params.output_format")
     }
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 enum SupportedEncryptionSchemes {
     #[serde(rename = "oaep")]
     RSA_OAEP,
@@ -69,7 +74,7 @@ enum SupportedEncryptionSchemes {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 enum SupportedMessageDigestAlgorithm {
     SHA1,
@@ -83,14 +88,7 @@ enum SupportedMessageDigestAlgorithm {
     SHA3_512,
 }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedOutputFormat {
-    Hex,
-    Base64,
-    Uint8Array,
-}
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     #[serde(rename = "pub_key")]
     public_key: String,
@@ -98,7 +96,7 @@ struct Params {
     encrypted_scheme: SupportedEncryptionSchemes,
     #[serde(rename = "digest_alg")]
     message_digest_algorithm: Option<SupportedMessageDigestAlgorithm>,
-    output_format: SupportedOutputFormat,
+    output_format: OutputEncoding,
 }
 
 create_me_daddy!();
@@ -118,7 +116,7 @@ create_me_daddy!();
 ///         "pub_key": PEM,
 ///         "scheme": SupportedEncryptionSchemes,
 ///         "digest_alg": Option<SupportedMessageDigestAlgorithm>
-///         "output_format": SupportedOutputFormat
+///         "output_format": OutputEncoding
 ///     }
 /// }
 /// ```
@@ -126,7 +124,8 @@ create_me_daddy!();
 ///     - PEM is pem encoded RSA public key
 ///     - SupportedEncryptionSchemes is enum of "oaep" and "pkcs1_v15"
 ///     - Option<SupportedMessageDigestAlgorithm> is optional enum of "sha1", "sha2-224", "sha2-256", "sha2-384", "sha2-512", "sha3-224", "sha3-256", "sha3-384", "sha3-512"
-///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
+///     - OutputEncoding is "hex", "base64", "uint8array", "raw" or an object with options, see [`OutputEncoding`](crate::OutputEncoding).
+///       The bare "hex", "base64" and "uint8array" names answer with an object like `{ "hex": ... }`, the rest with the encoded value itself
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -139,9 +138,10 @@ create_me_daddy!();
 ///   }
 /// }
 /// ```
+/// or `{ "Ok": `some answer` }` for the other formats.
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -201,7 +201,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `pub_key`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.pub_key",
+///     "message": {
+///       "en": "Missing field `params.pub_key`.",
+///       "ru": "Отсутствует обязательное поле `params.pub_key`."
+///     }
+///   }
 /// }
 /// ```
 pub struct RSAEncrypt;
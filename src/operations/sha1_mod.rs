@@ -1,26 +1,46 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sha1::{Digest, Sha1};
 
-use crate::{create_info_struct, utils::to_hex, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, impl_operation_request,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{DataRepresentation, DataRepresentationInput, OutputEncoding, SupportedFormats},
+    Operation, OperationError, DOCS_URL,
+};
 
-impl Operation<'_, DeserializeMeDaddy, String> for SHA1 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let input = request.input;
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for SHA1 {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let mut hasher = Sha1::new();
-        hasher.update(input.as_bytes());
-        let result = &hasher.finalize()[..];
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, output_format) = (
+            request.input.into_bytes(),
+            request
+                .params
+                .and_then(|params| params.output_format)
+                .unwrap_or_default(),
+        );
 
-        Ok(to_hex(result))
+        let mut hasher = Hasher::new(HashAlgorithm::SHA1)?;
+        hasher.update(&input);
+
+        output_format.encode(hasher.finalize(), "params.output_format")
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    output_format: Option<OutputEncoding>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct DeserializeMeDaddy {
-    input: String,
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+    params: Option<Params>,
 }
 
+impl_operation_request!();
+
 /// The SHA (Secure Hash Algorithm) hash functions were designed by the NSA. SHA-1 is the most established of the existing SHA hash functions, and it is used in a variety of security applications and protocols. However, SHA-1's collision resistance has been weakening as new attacks are discovered or improved.
 /// <br><br/>
 /// For more information go [here](https://wikipedia.org/wiki/SHA-1).
@@ -32,8 +52,14 @@ pub struct DeserializeMeDaddy {
 /// ``` json
 /// {
 ///     "input": string,
+///     "params": {
+///         "output_format": Option<OutputEncoding>
+///     }
 /// }
 /// ```
+/// #### where
+///     - params may be left out
+///     - OutputEncoding is "hex" by default, see [`OutputEncoding`](crate::OutputEncoding)
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -44,7 +70,7 @@ pub struct DeserializeMeDaddy {
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -84,7 +110,14 @@ pub struct DeserializeMeDaddy {
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `input`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "input",
+///     "message": {
+///       "en": "Missing field `input`.",
+///       "ru": "Отсутствует обязательное поле `input`."
+///     }
+///   }
 /// }
 /// ```
 pub struct SHA1;
@@ -1,51 +1,38 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
 
-use crate::{create_info_struct, create_me_daddy, utils::to_hex, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{DataRepresentation, DataRepresentationInput, OutputEncoding},
+    Operation, OperationError, DOCS_URL,
+};
 
-impl Operation<'_, DeserializeMeDaddy, String> for SHA2 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, size) = (request.input, request.params.size);
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for SHA2 {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let result = match size {
-            SupportedSHA2Size::SHA224 => {
-                let mut hasher = Sha224::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA2Size::SHA256 => {
-                let mut hasher = Sha256::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA2Size::SHA384 => {
-                let mut hasher = Sha384::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA2Size::SHA512 => {
-                let mut hasher = Sha512::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA2Size::SHA512_224 => {
-                let mut hasher = Sha512_224::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA2Size::SHA512_256 => {
-                let mut hasher = Sha512_256::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-        };
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, size, output_format) = (
+            request.input.into_bytes(),
+            request.params.size,
+            request.params.output_format.unwrap_or_default(),
+        );
 
-        Ok(result)
+        let mut hasher = Hasher::new(match size {
+            SupportedSHA2Size::SHA224 => HashAlgorithm::SHA224,
+            SupportedSHA2Size::SHA256 => HashAlgorithm::SHA256,
+            SupportedSHA2Size::SHA384 => HashAlgorithm::SHA384,
+            SupportedSHA2Size::SHA512 => HashAlgorithm::SHA512,
+            SupportedSHA2Size::SHA512_224 => HashAlgorithm::SHA512_224,
+            SupportedSHA2Size::SHA512_256 => HashAlgorithm::SHA512_256,
+        })?;
+        hasher.update(&input);
+
+        output_format.encode(hasher.finalize(), "params.output_format")
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 enum SupportedSHA2Size {
     SHA224,
@@ -56,9 +43,10 @@ enum SupportedSHA2Size {
     SHA512_256,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     size: SupportedSHA2Size,
+    output_format: Option<OutputEncoding>,
 }
 
 create_me_daddy!();
@@ -75,12 +63,14 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "size": SupportedSHA2Size
+///         "size": SupportedSHA2Size,
+///         "output_format": Option<OutputEncoding>
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedSHA2Size is enum of "sha224", "sha256", "sha384", "sha512", "sha512_224", "sha512_256"
+///     - OutputEncoding is "hex" by default, see [`OutputEncoding`](crate::OutputEncoding)
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -91,7 +81,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -138,7 +128,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `params`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params",
+///     "message": {
+///       "en": "Missing field `params`.",
+///       "ru": "Отсутствует обязательное поле `params`."
+///     }
+///   }
 /// }
 /// ```
 pub struct SHA2;
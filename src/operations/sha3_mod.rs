@@ -1,40 +1,35 @@
-use crate::{create_info_struct, create_me_daddy, utils::to_hex, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::hashing::{HashAlgorithm, Hasher},
+    utils::{DataRepresentation, DataRepresentationInput, OutputEncoding},
+    Operation, OperationError, DOCS_URL,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
-impl Operation<'_, DeserializeMeDaddy, String> for SHA3 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, size) = (request.input, request.params.size);
+impl Operation<'_, DeserializeMeDaddy, DataRepresentation> for SHA3 {
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
 
-        let result = match size {
-            SupportedSHA3Size::SHA224 => {
-                let mut hasher = Sha3_224::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA3Size::SHA256 => {
-                let mut hasher = Sha3_256::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA3Size::SHA384 => {
-                let mut hasher = Sha3_384::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-            SupportedSHA3Size::SHA512 => {
-                let mut hasher = Sha3_512::new();
-                hasher.update(input);
-                to_hex(&hasher.finalize()[..])
-            }
-        };
+    fn run(&self, request: DeserializeMeDaddy) -> Result<DataRepresentation, OperationError> {
+        let (input, size, output_format) = (
+            request.input.into_bytes(),
+            request.params.size,
+            request.params.output_format.unwrap_or_default(),
+        );
 
-        Ok(result)
+        let mut hasher = Hasher::new(match size {
+            SupportedSHA3Size::SHA224 => HashAlgorithm::SHA3_224,
+            SupportedSHA3Size::SHA256 => HashAlgorithm::SHA3_256,
+            SupportedSHA3Size::SHA384 => HashAlgorithm::SHA3_384,
+            SupportedSHA3Size::SHA512 => HashAlgorithm::SHA3_512,
+        })?;
+        hasher.update(&input);
+
+        output_format.encode(hasher.finalize(), "params.output_format")
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 enum SupportedSHA3Size {
     SHA224,
@@ -43,9 +38,10 @@ enum SupportedSHA3Size {
     SHA512,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
     size: SupportedSHA3Size,
+    output_format: Option<OutputEncoding>,
 }
 
 create_me_daddy!();
@@ -62,12 +58,14 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "size": SupportedSHA2Size
+///         "size": SupportedSHA2Size,
+///         "output_format": Option<OutputEncoding>
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedSHA2Size is enum of "sha224", "sha256", "sha384", "sha512", "sha512_224", "sha512_256"
+///     - OutputEncoding is "hex" by default, see [`OutputEncoding`](crate::OutputEncoding)
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -78,7 +76,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -125,7 +123,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `params`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params",
+///     "message": {
+///       "en": "Missing field `params`.",
+///       "ru": "Отсутствует обязательное поле `params`."
+///     }
+///   }
 /// }
 /// ```
 pub struct SHA3;
@@ -0,0 +1,248 @@
+use std::ops::Range;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    utils::{build_regex, DataRepresentation},
+    BranchErrorPolicy, Operation, OperationError, DOCS_URL,
+};
+
+impl Operation<'_, DeserializeMeDaddy, Vec<String>> for Subsection {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<Vec<String>, OperationError> {
+        let (input, settings) = (request.input.into_string()?, request.params.settings()?);
+
+        Ok(settings
+            .sections(&input)
+            .into_iter()
+            .map(|section| input[section].to_string())
+            .collect())
+    }
+}
+
+/// Parameters of a Subsection step as the recipe uses them.
+pub(crate) struct SubsectionSettings {
+    regex: Regex,
+    capture_group: usize,
+    global: bool,
+    pub on_error: BranchErrorPolicy,
+}
+
+impl SubsectionSettings {
+    /// Byte ranges of the selected sections, in order and never overlapping.
+    /// Matches where the capture group did not participate are skipped.
+    pub fn sections(&self, input: &str) -> Vec<Range<usize>> {
+        let sections = self
+            .regex
+            .captures_iter(input)
+            .filter_map(|captures| captures.get(self.capture_group))
+            .map(|section| section.range());
+
+        match self.global {
+            true => sections.collect(),
+            false => sections.take(1).collect(),
+        }
+    }
+}
+
+impl Subsection {
+    pub(crate) fn settings(&self, params: &Value) -> Result<SubsectionSettings, OperationError> {
+        self.validate(&json!({ "input": "", "params": params }).to_string())?
+            .params
+            .settings()
+    }
+}
+
+/// Puts the output of every section back in place of it, sections without output are removed.
+pub(crate) fn restore(
+    input: &str,
+    sections: &[Range<usize>],
+    outputs: Vec<Option<DataRepresentation>>,
+) -> DataRepresentation {
+    let mut restored = Vec::with_capacity(input.len());
+    let mut last = 0;
+
+    for (section, output) in sections.iter().zip(outputs) {
+        restored.extend_from_slice(&input.as_bytes()[last..section.start]);
+        if let Some(output) = output {
+            restored.extend(output.into_bytes());
+        }
+        last = section.end;
+    }
+    restored.extend_from_slice(&input.as_bytes()[last..]);
+
+    DataRepresentation::from_bytes(restored)
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+struct Params {
+    regex: String,
+    capture_group: Option<usize>,
+    case_insensitive: Option<bool>,
+    #[schemars(extend("default" = true))]
+    global: Option<bool>,
+    on_error: Option<BranchErrorPolicy>,
+}
+
+impl Params {
+    fn settings(self) -> Result<SubsectionSettings, OperationError> {
+        let regex = build_regex(
+            &self.regex,
+            self.case_insensitive.unwrap_or_default(),
+            false,
+            "params.regex",
+        )?;
+
+        let capture_group = self.capture_group.unwrap_or_default();
+        if capture_group >= regex.captures_len() {
+            return Err(OperationError::invalid_parameter(
+                "params.capture_group",
+                format!(
+                    "Regex has only {} capture groups.",
+                    regex.captures_len() - 1
+                ),
+                format!(
+                    "В регулярном выражении только {} групп захвата.",
+                    regex.captures_len() - 1
+                ),
+            ));
+        }
+
+        Ok(SubsectionSettings {
+            regex,
+            capture_group,
+            global: self.global.unwrap_or(true),
+            on_error: self.on_error.unwrap_or_default(),
+        })
+    }
+}
+
+create_me_daddy!();
+
+/// Selects parts of the input with a regular expression and runs the following steps of a recipe only on them, until a Merge step or the end of the recipe.
+/// <br><br/>
+/// Every selected part is put back in place of it, the rest of the input is left untouched.
+/// `capture_group` selects only one group of every match, 0 is the whole match. With `global` set to false only the first match is used.
+/// When the operation is sent on its own it returns the list of selected parts.
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Subsection with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "regex": string,
+///         "capture_group": Option<usize>,
+///         "case_insensitive": Option<bool>,
+///         "global": Option<bool>,
+///         "on_error": Option<BranchErrorPolicy>
+///     }
+/// }
+/// ```
+/// #### where
+///     - capture_group is 0 by default
+///     - global is true by default
+///     - BranchErrorPolicy is enum of "fail_fast", "ignore", "keep_original", "fail_fast" by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Subsection
+///
+/// {
+///     "input": "{\"user\": \"YWRtaW4=\", \"pass\": \"aHVudGVyMg==\"}",
+///     "params": {
+///         "regex": "\"([A-Za-z0-9+/]+=*)\"",
+///         "capture_group": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": ["user", "YWRtaW4=", "pass", "aHVudGVyMg=="]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/recipe
+///
+/// {
+///     "input": "{\"user\": \"YWRtaW4=\", \"pass\": \"aHVudGVyMg==\"}",
+///     "recipe": [
+///         { "op": "Subsection", "params": { "regex": "[A-Za-z0-9+/]{4,}=+" } },
+///         { "op": "FromBase64", "params": {} },
+///         { "op": "Merge" }
+///     ]
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": {
+///     "output": "{\"user\": \"admin\", \"pass\": \"hunter2\"}",
+///     "steps": [
+///       ["YWRtaW4=", "aHVudGVyMg=="],
+///       ["admin", "hunter2"],
+///       "{\"user\": \"admin\", \"pass\": \"hunter2\"}"
+///     ]
+///   }
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Subsection
+///
+/// {
+///     "input": "abc",
+///     "params": {
+///         "regex": "b",
+///         "capture_group": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.capture_group",
+///     "message": {
+///       "en": "Regex has only 0 capture groups.",
+///       "ru": "В регулярном выражении только 0 групп захвата."
+///     }
+///   }
+/// }
+/// ```
+pub struct Subsection;
+
+const NAME: &str = "Subsection";
+const DESCRIPTION_EN: &str = "Selects parts of the input with a regular expression and runs the following steps of a recipe only on them, until a Merge step or the end of the recipe.";
+const DESCRIPTION_RU: &str = "Выбирает части входных данных с помощью регулярного выражения и выполняет следующие шаги рецепта только для них, до шага Merge или конца рецепта.";
+
+const INFO_URL: Option<&str> = None;
+
+create_info_struct!(
+    SubsectionInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
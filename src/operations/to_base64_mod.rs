@@ -1,17 +1,23 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{create_info_struct, create_me_daddy, libs::base64::to_base64, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, libs::base64::to_base64, utils::DataRepresentationInput,
+    Operation, OperationError, DOCS_URL,
+};
 
 impl Operation<'_, DeserializeMeDaddy, String> for ToBase64 {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, alphabet) = (request.input, request.params.alphabet);
-        to_base64(input.as_bytes(), alphabet)
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::ByteArray;
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, alphabet) = (request.input.into_bytes(), request.params.alphabet);
+        to_base64(&input, alphabet)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 pub struct Params {
+    #[schemars(extend("default" = "A-Za-z0-9+/="))]
     alphabet: Option<String>,
 }
 
@@ -45,7 +51,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -94,7 +100,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `input`"
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "input",
+///     "message": {
+///       "en": "Missing field `input`.",
+///       "ru": "Отсутствует обязательное поле `input`."
+///     }
+///   }
 /// }
 /// ```
 pub struct ToBase64;
@@ -1,25 +1,38 @@
-use num::{BigInt, Num};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
 
-use crate::{create_info_struct, create_me_daddy, Operation, DOCS_URL};
+use crate::{
+    create_info_struct, create_me_daddy, utils::DataRepresentationInput, Operation, OperationError,
+    DOCS_URL,
+};
 
 impl Operation<'_, DeserializeMeDaddy, String> for ToBase {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
+    const INPUT_TYPE: DataRepresentationInput = DataRepresentationInput::BigNumber;
+
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
         let (input, radix) = (request.input, request.params.radix);
 
         #[allow(non_snake_case)]
-        let big_D_number = BigInt::from_str_radix(&input, 10)
-            .map_err(|_| "Invalid symbols found in string".to_string())?;
+        let big_D_number = input.into_big_number()?;
         Ok(big_D_number.to_str_radix(radix))
     }
 }
 
-#[derive(Deserialize, Validate)]
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
 pub struct Params {
     #[validate(maximum = 36)]
     #[validate(minimum = 2)]
+    #[schemars(range(min = 2, max = 36))]
     radix: u32,
 }
 
@@ -53,7 +66,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -94,7 +107,6 @@ create_me_daddy!();
 /// ``` http
 /// POST /api/ToBase
 /// content_type: application/json; charset=utf-8
-///
 /// {
 ///     "input": "69",
 ///     "params": {
@@ -104,10 +116,16 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Missing field `radix`."
+///   "Err": {
+///     "code": "missing_field",
+///     "param": "params.radix",
+///     "message": {
+///       "en": "Missing field `params.radix`.",
+///       "ru": "Отсутствует обязательное поле `params.radix`."
+///     }
+///   }
 /// }
 /// ```
-
 pub struct ToBase;
 
 const NAME: &str = "ToBase";
@@ -1,25 +1,29 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     create_info_struct, create_me_daddy,
-    libs::vigenere_trait::VigenereCipher,
-    utils::{sub, SupportedLanguages},
-    Operation, DOCS_URL,
+    libs::{alphabet::Language, vigenere_trait::VigenereCipher},
+    utils::sub,
+    Operation, OperationError, DOCS_URL,
 };
 
 impl VigenereCipher for VigenereCipherDecode {}
 
 impl Operation<'_, DeserializeMeDaddy, String> for VigenereCipherDecode {
-    fn do_black_magic(&self, request: &str) -> Result<String, String> {
-        let request = self.validate(request)?;
-        let (input, lang, key) = (request.input, request.params.lang, request.params.key);
-        <Self as VigenereCipher>::cipher(lang, &key, &input, sub)
+    fn run(&self, request: DeserializeMeDaddy) -> Result<String, OperationError> {
+        let (input, lang, key) = (
+            request.input.into_string()?,
+            request.params.lang,
+            request.params.key,
+        );
+        <Self as VigenereCipher>::cipher(&lang, &key, &input, sub)
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, JsonSchema)]
 struct Params {
-    lang: SupportedLanguages,
+    lang: Language,
     key: String,
 }
 
@@ -37,13 +41,13 @@ create_me_daddy!();
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages,
+///         "lang": Language,
 ///         "key": String
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Language is one of "en", "ru", "ru_with_yo", "de", "uk", "el", "latin_digits" or a custom alphabet { "lower": string, "upper": Option<string> }
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -54,7 +58,7 @@ create_me_daddy!();
 /// ```
 /// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
 /// ``` json
-/// { "Err": `error message` }
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
 /// ```
 /// # Examples
 /// ## №1
@@ -80,7 +84,7 @@ create_me_daddy!();
 /// POST /api/VigenereCipherDecode
 ///
 /// {
-///     "input": "Ееклыз, Осж!",
+///     "input": "Щыжщпэ, Кяъ!",
 ///     "params": {
 ///         "lang": "ru",
 ///         "key": "ключ"
@@ -107,7 +111,14 @@ create_me_daddy!();
 /// ```http
 /// HTTP/1.1 400 Bad Request
 /// {
-///   "Err": "Unknown variant `else`, expected one of `En`, `Ru`, `RuAlpWithYo`."
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.lang",
+///     "message": {
+///       "en": "Invalid value of `params.lang`: one of `en`, `ru`, `ru_with_yo`, `de`, `uk`, `el`, `latin_digits` or a custom alphabet.",
+///       "ru": "Недопустимое значение параметра `params.lang`: one of `en`, `ru`, `ru_with_yo`, `de`, `uk`, `el`, `latin_digits` or a custom alphabet."
+///     }
+///   }
 /// }
 /// ```
 pub struct VigenereCipherDecode;
@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_valid::Validate;
+
+use crate::{
+    create_info_struct, create_me_daddy,
+    libs::{
+        alphabet::{Alphabet, Language},
+        ciphers::caesar_cipher,
+        scoring::{
+            chi_squared, index_of_coincidence, score_candidates, scored_language, Candidate,
+        },
+        vigenere_trait::VigenereCipher,
+    },
+    policy,
+    utils::{sub, SupportedLanguages},
+    Operation, OperationError, DOCS_URL,
+};
+
+impl VigenereCipher for VigenereCipherKeyRecovery {}
+
+impl Operation<'_, DeserializeMeDaddy, Vec<Candidate<VigenereKey>>> for VigenereCipherKeyRecovery {
+    fn validate(&self, request: &str) -> Result<DeserializeMeDaddy, OperationError> {
+        let request = self.deserialize(request)?;
+        request
+            .params
+            .validate()
+            .map_err(|err| OperationError::from_validation("params", err))?;
+        Ok(request)
+    }
+
+    fn run(
+        &self,
+        request: DeserializeMeDaddy,
+    ) -> Result<Vec<Candidate<VigenereKey>>, OperationError> {
+        let (input, lang, limit, max_key_length) = (
+            request.input.into_string()?,
+            scored_language(&request.params.lang)?,
+            request.params.limit.unwrap_or(DEFAULT_LIMIT),
+            request
+                .params
+                .max_key_length
+                .unwrap_or(DEFAULT_MAX_KEY_LENGTH),
+        );
+        let alphabet = request.params.lang.alphabet()?;
+
+        let letters = input
+            .chars()
+            .filter_map(|c| alphabet.index_of(c))
+            .collect_vec();
+        if letters.len() < 2 {
+            return Err(OperationError::invalid_input(
+                "Input is too short to recover the key.",
+                "Входные данные слишком короткие для восстановления ключа.",
+            ));
+        }
+
+        let mut keys: Vec<(String, f64)> = Vec::new();
+        for (length, ic) in key_lengths(&letters, alphabet.length(), max_key_length)? {
+            policy::checkpoint()?;
+            let key = recover_key(&letters, &alphabet, lang, length);
+            let key = match key_period(&key) {
+                period if period < length => recover_key(&letters, &alphabet, lang, period),
+                _ => key,
+            };
+            let key = key
+                .iter()
+                .map(|&idx| alphabet.char_at(idx, false))
+                .collect::<String>();
+
+            if !keys.iter().any(|(known, _)| *known == key) {
+                keys.push((key, ic));
+            }
+            if keys.len() == limit {
+                break;
+            }
+        }
+
+        let candidates = keys
+            .into_iter()
+            .map(|(key, index_of_coincidence)| {
+                policy::checkpoint()?;
+                let output =
+                    <Self as VigenereCipher>::cipher(&request.params.lang, &key, &input, sub)?;
+                Ok((
+                    VigenereKey {
+                        key,
+                        index_of_coincidence,
+                    },
+                    output,
+                ))
+            })
+            .collect::<Result<Vec<_>, OperationError>>()?;
+
+        let mut candidates = score_candidates(candidates, lang)?;
+        for candidate in &mut candidates {
+            candidate.output = candidate.output.chars().take(PREVIEW_LENGTH).collect();
+        }
+
+        Ok(candidates)
+    }
+}
+
+const DEFAULT_LIMIT: usize = 3;
+const DEFAULT_MAX_KEY_LENGTH: usize = 20;
+const PREVIEW_LENGTH: usize = 80;
+
+/// Length of the sequences Kasiski examination looks for repeats of.
+const KASISKI_SEQUENCE: usize = 3;
+/// A length is left out when one of its divisors has at least this share of its index of
+/// coincidence, the columns of a multiple of the key length are as even as the key length ones.
+const MULTIPLE_RATIO: f64 = 0.9;
+/// Share of the letters of a key that have to repeat with a shorter period for the key to be
+/// recovered again with that period.
+const PERIOD_AGREEMENT: f64 = 0.75;
+
+/// Key to send to VigenereCipherDecode with the index of coincidence of its length.
+#[derive(Serialize)]
+pub struct VigenereKey {
+    key: String,
+    index_of_coincidence: f64,
+}
+
+/// Key lengths from the most to the least likely, each with the mean index of coincidence
+/// of its columns. Lengths are ranked by that index plus the share of distances between
+/// repeated sequences they divide, every column has at least two letters and multiples
+/// of a length explaining their index are left out.
+fn key_lengths(
+    letters: &[usize],
+    alphabet_length: usize,
+    max_key_length: usize,
+) -> Result<Vec<(usize, f64)>, OperationError> {
+    let distances = repeat_distances(letters);
+
+    let lengths = (1..=max_key_length.min(letters.len() / 2))
+        .map(|length| {
+            policy::checkpoint()?;
+            let ic = (0..length)
+                .filter_map(|start| {
+                    let column = letters
+                        .iter()
+                        .skip(start)
+                        .step_by(length)
+                        .copied()
+                        .collect_vec();
+                    index_of_coincidence(&column, alphabet_length)
+                })
+                .sum::<f64>()
+                / length as f64;
+
+            let kasiski = match (length, distances.len()) {
+                (1, _) | (_, 0) => 0.0,
+                _ => {
+                    distances
+                        .iter()
+                        .filter(|&&distance| distance.is_multiple_of(length))
+                        .count() as f64
+                        / distances.len() as f64
+                }
+            };
+
+            Ok((length, ic, ic + kasiski))
+        })
+        .collect::<Result<Vec<_>, OperationError>>()?;
+
+    Ok(lengths
+        .iter()
+        .filter(|(length, ic, _)| {
+            !lengths.iter().any(|(divisor, divisor_ic, _)| {
+                divisor < length
+                    && length.is_multiple_of(*divisor)
+                    && *divisor_ic >= MULTIPLE_RATIO * ic
+            })
+        })
+        .sorted_by(|a, b| b.2.total_cmp(&a.2).then(a.0.cmp(&b.0)))
+        .map(|&(length, ic, _)| (length, ic))
+        .collect())
+}
+
+/// Distances between consecutive occurrences of every repeated sequence of letters.
+fn repeat_distances(letters: &[usize]) -> Vec<usize> {
+    let mut last_seen: HashMap<&[usize], usize> = HashMap::new();
+    let mut distances = Vec::new();
+
+    for (position, sequence) in letters.windows(KASISKI_SEQUENCE).enumerate() {
+        if let Some(previous) = last_seen.insert(sequence, position) {
+            distances.push(position - previous);
+        }
+    }
+
+    distances
+}
+
+/// Every letter of the key is the shift of its column closest to the letter frequencies.
+fn recover_key(
+    letters: &[usize],
+    alphabet: &Alphabet,
+    lang: SupportedLanguages,
+    length: usize,
+) -> Vec<usize> {
+    (0..length)
+        .map(|start| {
+            let column = letters
+                .iter()
+                .skip(start)
+                .step_by(length)
+                .map(|&idx| alphabet.char_at(idx, false))
+                .collect::<String>();
+
+            (0..alphabet.length())
+                .map(|shift| {
+                    let decrypted = caesar_cipher(&column, alphabet, -(shift as i64));
+                    (
+                        shift,
+                        chi_squared(&decrypted, lang).unwrap_or(f64::INFINITY),
+                    )
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(shift, _)| shift)
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Shortest period most letters of the key repeat with. A key found for a multiple of the
+/// real length is that key repeated, except for the letters its shorter columns got wrong.
+fn key_period(key: &[usize]) -> usize {
+    (1..=key.len())
+        .filter(|period| key.len().is_multiple_of(*period))
+        .find(|&period| {
+            let repeated = (0..period)
+                .map(|start| {
+                    key.iter()
+                        .skip(start)
+                        .step_by(period)
+                        .counts()
+                        .into_values()
+                        .max()
+                        .unwrap_or_default()
+                })
+                .sum::<usize>();
+            repeated as f64 >= PERIOD_AGREEMENT * key.len() as f64
+        })
+        .unwrap_or(key.len())
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Validate)]
+struct Params {
+    lang: Language,
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1), extend("default" = 3))]
+    limit: Option<usize>,
+    #[validate(maximum = 100)]
+    #[validate(minimum = 1)]
+    #[schemars(range(min = 1, max = 100), extend("default" = 20))]
+    max_key_length: Option<usize>,
+}
+
+create_me_daddy!();
+
+/// Recovers the key of the Vigenere cipher from the ciphertext alone. The key length is estimated by Kasiski examination and the index of coincidence, every letter of the key by frequency analysis of its column. Returns candidate keys with the start of the decrypted text, best first.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Vigenère_cipher#Cryptanalysis)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/VigenereCipherKeyRecovery with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Language,
+///         "limit": Option<usize>,
+///         "max_key_length": Option<usize>
+///     }
+/// }
+/// ```
+/// #### where
+///     - Language is one of "en", "ru", "ru_with_yo", "latin_digits", columns are scored by english or russian letter frequencies
+///     - limit is maximum number of candidate keys, 3 by default
+///     - max_key_length is the longest key length tried from 1 to 100, 20 by default
+///     - key of a candidate is the one to send to VigenereCipherDecode, output is the first 80 characters of the decrypted text
+///     - the longer the ciphertext the better, every letter of the key needs a few dozen letters of its own
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": [ { "key": string, "index_of_coincidence": f64, "output": string, "chi_squared": f64 } ] }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": { "code": `error code`, "param": `offending field or null`, "message": { "en": `message`, "ru": `message` } } }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/VigenereCipherKeyRecovery
+///
+/// {
+///     "input": "Tx iof elq prdx at gtqqg, ve amg gsi icedx at gtqqg, ve amg gsi mur zj iwfosy, wg hee hup ess bq jacytwtbrdw, uh jlw fvr ptaqu zj nsytir, wg hee hup ibcps sr wanvqrhwmfm, ve amg gsi esndsz cs Wmsvg, tx iof elq grlwab bq Hmfxyieg, ve amg gsi edetrs cs ssbs, ve amg gsi iwaeid cs oiedntv.",
+///     "params": {
+///         "lang": "en",
+///         "limit": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": [
+///     {
+///       "key": "lemon",
+///       "index_of_coincidence": 2.263384677338166,
+///       "output": "It was the best of times, it was the worst of times, it was the age of wisdom, i",
+///       "chi_squared": 66.11899578310566
+///     },
+///     {
+///       "key": "e",
+///       "index_of_coincidence": 1.2824714115036695,
+///       "output": "Pt ekb ahm lnzt wp cpmmc, ra wic coe eyazt wp cpmmc, ra wic coe iqn vf esbkou, s",
+///       "chi_squared": 642.4239682273046
+///     }
+///   ]
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/VigenereCipherKeyRecovery
+///
+/// {
+///     "input": "Мьг иблпйхуатп ьггжу неящдя оысъ чл взэою, бксвчй шгиблпйхуачй ьггжк льывюиьцжщк ъм-имщггэ. Нпь ычгпкцмиж н вецр Мшхщлифуу. Эпшю ксшювк, вре цюд шец а имкея ы мщщврь щ та вецр тзкшфкррлбшй-бкмродкшрбшф, ж елеэщтцю гэсс, оьщ ль цщдьь сжйж ь ляц н мычщк ышчг.",
+///     "params": {
+///         "lang": "ru",
+///         "limit": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// {
+///   "Ok": [
+///     {
+///       "key": "ключ",
+///       "index_of_coincidence": 1.5481146304675715,
+///       "output": "Все счастливые семьи похожи друг на друга, каждая несчастливая семья несчастлива",
+///       "chi_squared": 58.34820745936682
+///     },
+///     {
+///       "key": "ычбэщшбюай",
+///       "index_of_coincidence": 1.3714285714285714,
+///       "output": "Сев лиуолхкеыо яклех ньдвгв хгрь чв зрьсе, ййувоо бвлиуолхкеаи яклем луалэлгюеык",
+///       "chi_squared": 139.33581019930247
+///     }
+///   ]
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/VigenereCipherKeyRecovery
+///
+/// {
+///     "input": "Hi!",
+///     "params": {
+///         "lang": "en",
+///         "max_key_length": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": {
+///     "code": "invalid_parameter",
+///     "param": "params.max_key_length",
+///     "message": {
+///       "en": "Invalid value of `params.max_key_length`: The number must be `>= 1`.",
+///       "ru": "Недопустимое значение параметра `params.max_key_length`: The number must be `>= 1`."
+///     }
+///   }
+/// }
+/// ```
+pub struct VigenereCipherKeyRecovery;
+
+const NAME: &str = "VigenereCipherKeyRecovery";
+const DESCRIPTION_EN: &str = "Recovers the key of the Vigenere cipher from the ciphertext alone. The key length is estimated by Kasiski examination and the index of coincidence, every letter of the key by frequency analysis of its column. Returns candidate keys with the start of the decrypted text, best first.";
+const DESCRIPTION_RU: &str = "Восстанавливает ключ шифра Виженера только по шифротексту. Длина ключа оценивается методом Касиски и индексом совпадений, каждая буква ключа — частотным анализом своего столбца. Возвращает варианты ключей с началом расшифрованного текста, начиная с лучшего.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Vigenère_cipher#Cryptanalysis");
+
+create_info_struct!(
+    VigenereCipherKeyRecoveryInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
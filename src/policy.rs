@@ -0,0 +1,168 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::OperationError;
+
+/// Limits every operation and recipe run inside [`ExecutionPolicy::enforce`] is held to.
+/// Costs are checked before an expensive operation starts, the input size before every
+/// operation and the deadline and cancellation between steps, items of a batch and chunks
+/// of long loops. Breaking any of them is a `resource_limit_exceeded` error.
+/// `None` leaves the value unlimited.
+/// ``` rust
+/// let policy = ExecutionPolicy {
+///     max_bcrypt_cost: Some(12),
+///     timeout: Some(Duration::from_secs(5)),
+///     ..Default::default()
+/// };
+/// let output = policy.enforce(&CancellationToken::new(), || recipe.bake(input));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionPolicy {
+    /// Argon2 memory cost in KiB.
+    pub max_memory_cost: Option<u32>,
+    /// Argon2 iterations.
+    pub max_time_cost: Option<u32>,
+    /// Argon2 lanes, each hashed by its own thread.
+    pub max_parallelism: Option<u32>,
+    /// Bcrypt rounds.
+    pub max_bcrypt_cost: Option<u32>,
+    /// Bytes of the input of every operation, including every step of a recipe.
+    pub max_input_size: Option<usize>,
+    /// Wall-clock time from the start of [`ExecutionPolicy::enforce`].
+    pub timeout: Option<Duration>,
+}
+
+/// Stops a running operation or recipe from another thread at its next check.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl ExecutionPolicy {
+    /// Runs `f` on this thread with the policy and the token applied to everything it runs.
+    /// An inner call replaces the policy of an outer one until it returns.
+    pub fn enforce<T>(&self, token: &CancellationToken, f: impl FnOnce() -> T) -> T {
+        Execution {
+            policy: self.clone(),
+            deadline: self.timeout.map(|timeout| Instant::now() + timeout),
+            token: token.clone(),
+        }
+        .enter(f)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Execution>> = const { RefCell::new(None) };
+}
+
+/// Policy of the running [`ExecutionPolicy::enforce`] call.
+#[derive(Clone)]
+pub(crate) struct Execution {
+    policy: ExecutionPolicy,
+    deadline: Option<Instant>,
+    token: CancellationToken,
+}
+
+impl Execution {
+    pub fn current() -> Option<Self> {
+        CURRENT.with_borrow(Clone::clone)
+    }
+
+    /// Applies the execution to `f`, threads spawned inside enter it again.
+    pub fn enter<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Execution>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.replace(Some(self)));
+        f()
+    }
+}
+
+fn with_current(
+    f: impl FnOnce(&Execution) -> Result<(), OperationError>,
+) -> Result<(), OperationError> {
+    CURRENT.with_borrow(|execution| execution.as_ref().map_or(Ok(()), f))
+}
+
+/// Fails when the deadline passed or the token was cancelled.
+pub(crate) fn checkpoint() -> Result<(), OperationError> {
+    with_current(|execution| {
+        if execution.token.is_cancelled() {
+            return Err(OperationError::resource_limit_exceeded(
+                "cancellation",
+                "Execution was cancelled.",
+                "Выполнение было отменено.",
+            ));
+        }
+
+        match (execution.deadline, execution.policy.timeout) {
+            (Some(deadline), Some(timeout)) if Instant::now() >= deadline => {
+                Err(OperationError::resource_limit_exceeded(
+                    "deadline",
+                    format!("Execution took longer than {} ms.", timeout.as_millis()),
+                    format!("Выполнение заняло больше {} мс.", timeout.as_millis()),
+                ))
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Whether the running policy limits the input size, so it is worth measuring.
+pub(crate) fn limits_input() -> bool {
+    CURRENT.with_borrow(|execution| {
+        execution
+            .as_ref()
+            .is_some_and(|execution| execution.policy.max_input_size.is_some())
+    })
+}
+
+pub(crate) fn check_input_size(size: usize) -> Result<(), OperationError> {
+    with_current(|execution| match execution.policy.max_input_size {
+        Some(max) if size > max => Err(OperationError::resource_limit_exceeded(
+            "input",
+            format!("Input is {size} bytes, at most {max} bytes are allowed."),
+            format!("Входные данные занимают {size} байт, допускается не больше {max} байт."),
+        )),
+        _ => Ok(()),
+    })
+}
+
+/// Fails when `cost` of `param` is above the limit `limit` picks from the policy.
+pub(crate) fn check_cost(
+    param: &str,
+    cost: u32,
+    limit: fn(&ExecutionPolicy) -> Option<u32>,
+) -> Result<(), OperationError> {
+    with_current(|execution| match limit(&execution.policy) {
+        Some(max) if cost > max => Err(OperationError::resource_limit_exceeded(
+            param,
+            format!("`{param}` is {cost}, at most {max} is allowed."),
+            format!("Значение `{param}` равно {cost}, допускается не больше {max}."),
+        )),
+        _ => Ok(()),
+    })
+}
@@ -0,0 +1,413 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    impl_operation_request,
+    operations::{capture, merge_branches, restore_sections},
+    policy,
+    utils::{DataRepresentation, SupportedFormats},
+    BranchErrorPolicy, ConditionalJump, Fork, Jump, Label, Merge, Operation, OperationError,
+    Operations, Register, Subsection,
+};
+
+/// One step of a [`Recipe`]: the operation to run and its `params` object,
+/// exactly as it would be sent to /api/{op}.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecipeStep {
+    pub op: Operations,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl RecipeStep {
+    pub fn new(op: Operations, params: Value) -> Self {
+        Self { op, params }
+    }
+}
+
+/// Ordered list of operations where the output of every step becomes the `input` of the next one.
+/// The value is converted to the input type each operation declares before it runs.
+/// Steps between a Fork and its Merge run once for every branch of the input,
+/// steps between a Subsection and its Merge only on the parts its regex selects.
+/// ``` json
+/// [
+///     { "op": "FromBase64", "params": { "alphabet": null } },
+///     { "op": "SHA2", "params": { "size": "sha256" } }
+/// ]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct Recipe {
+    pub steps: Vec<RecipeStep>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecipeOutput {
+    pub output: DataRepresentation,
+    /// Output of every executed step, a step reached again by a jump appears again.
+    pub steps: Vec<DataRepresentation>,
+}
+
+impl Recipe {
+    pub fn new(steps: Vec<RecipeStep>) -> Self {
+        Self { steps }
+    }
+
+    pub fn bake(&self, input: DataRepresentation) -> Result<RecipeOutput, OperationError> {
+        bake_steps(&self.steps, 0, RecipeState::new(input))
+    }
+}
+
+/// Where a recipe run stands between two steps: the data the next step gets,
+/// the registers captured so far and how many times every jump was taken.
+/// Serializable, so a paused run can be saved and resumed later.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecipeState {
+    /// Index of the step to run next.
+    pub next_step: usize,
+    pub data: DataRepresentation,
+    #[serde(default)]
+    pub registers: Vec<String>,
+    #[serde(default)]
+    jumps: HashMap<usize, usize>,
+}
+
+impl RecipeState {
+    /// State before the first step of a recipe.
+    pub fn new(input: DataRepresentation) -> Self {
+        Self {
+            next_step: 0,
+            data: input,
+            registers: Vec::new(),
+            jumps: HashMap::new(),
+        }
+    }
+}
+
+/// Runs `steps`, which start at index `offset` of the whole recipe, from `state` to the end.
+fn bake_steps(
+    steps: &[RecipeStep],
+    offset: usize,
+    mut state: RecipeState,
+) -> Result<RecipeOutput, OperationError> {
+    let mut outputs = Vec::with_capacity(steps.len());
+
+    while state.next_step < steps.len() {
+        bake_step(steps, offset, &mut state, &mut outputs)?;
+    }
+
+    Ok(RecipeOutput {
+        output: state.data,
+        steps: outputs,
+    })
+}
+
+/// Runs the step at `state.next_step` and moves `state` to the step that follows it.
+/// A Fork or Subsection runs everything up to its Merge once per branch, the output
+/// of such steps is the list of branch outputs. Registers captured by Register steps
+/// are substituted for `$R0`, `$R1`... in the params of every step.
+/// Every produced output is pushed to `outputs`. On error `state.data` is left empty.
+pub(crate) fn bake_step(
+    steps: &[RecipeStep],
+    offset: usize,
+    state: &mut RecipeState,
+    outputs: &mut Vec<DataRepresentation>,
+) -> Result<(), OperationError> {
+    let idx = state.next_step;
+    let step = &steps[idx];
+    let fail = |err| step_error(offset + idx, step, err);
+    policy::checkpoint().map_err(fail)?;
+    let params = substitute_registers(&step.params, &state.registers);
+    let output = std::mem::replace(&mut state.data, DataRepresentation::String(String::new()));
+
+    let (output, next_step) = match step.op {
+        Operations::Fork | Operations::Subsection => {
+            let merge = matching_merge(steps, idx);
+            let body = &steps[idx + 1..merge.unwrap_or(steps.len())];
+            let body_offset = offset + idx + 1;
+            let params = params_object(&params);
+            let registers = &state.registers;
+
+            let output = match step.op {
+                Operations::Fork => {
+                    let fork = Fork.settings(&params).map_err(fail)?;
+                    let branches = output
+                        .into_string()
+                        .map_err(fail)?
+                        .split(&fork.split_delimiter)
+                        .map(String::from)
+                        .collect();
+                    let merged = bake_branches(
+                        body,
+                        body_offset,
+                        branches,
+                        fork.on_error,
+                        registers,
+                        outputs,
+                    )?;
+
+                    let delimiter = match merge {
+                        Some(merge) => {
+                            let params = substitute_registers(&steps[merge].params, registers);
+                            Merge
+                                .delimiter(&params_object(&params))
+                                .map_err(|err| step_error(offset + merge, &steps[merge], err))?
+                        }
+                        None => None,
+                    };
+                    merge_branches(
+                        merged.into_iter().flatten().collect(),
+                        delimiter.as_deref().unwrap_or(&fork.merge_delimiter),
+                    )
+                }
+                _ => {
+                    let subsection = Subsection.settings(&params).map_err(fail)?;
+                    let input = output.into_string().map_err(fail)?;
+                    let sections = subsection.sections(&input);
+                    let baked = bake_branches(
+                        body,
+                        body_offset,
+                        sections
+                            .iter()
+                            .map(|section| input[section.clone()].to_string())
+                            .collect(),
+                        subsection.on_error,
+                        registers,
+                        outputs,
+                    )?;
+
+                    restore_sections(&input, &sections, baked)
+                }
+            };
+
+            match merge {
+                Some(merge) => {
+                    outputs.push(output.clone());
+                    (output, merge + 1)
+                }
+                None => (output, steps.len()),
+            }
+        }
+        Operations::Register => {
+            let extractor = Register.extractor(&params_object(&params)).map_err(fail)?;
+            state
+                .registers
+                .extend(capture(&extractor, &data_text(&output)));
+            outputs.push(output.clone());
+            (output, idx + 1)
+        }
+        Operations::Label => {
+            Label.name(&params_object(&params)).map_err(fail)?;
+            outputs.push(output.clone());
+            (output, idx + 1)
+        }
+        Operations::Jump | Operations::ConditionalJump => {
+            let jump = match step.op {
+                Operations::Jump => Jump.settings(&params_object(&params)),
+                _ => ConditionalJump.settings(&params_object(&params)),
+            }
+            .map_err(fail)?;
+            let target = find_label(steps, &jump.label).map_err(fail)?;
+
+            outputs.push(output.clone());
+            let taken = state.jumps.entry(idx).or_insert(0);
+            if *taken < jump.max_jumps && jump.should_jump(&data_text(&output)) {
+                *taken += 1;
+                (output, target)
+            } else {
+                (output, idx + 1)
+            }
+        }
+        _ => {
+            let output = step.op.run(output, &params).map_err(fail)?;
+            outputs.push(output.clone());
+            (output, idx + 1)
+        }
+    };
+
+    state.data = output;
+    state.next_step = next_step;
+    Ok(())
+}
+
+/// Runs `body` on every branch and pushes the branches and the outputs of every
+/// executed body step to `outputs`. Returns the output of every branch, `None` when
+/// the branch failed and the policy leaves it out.
+fn bake_branches(
+    body: &[RecipeStep],
+    offset: usize,
+    branches: Vec<String>,
+    on_error: BranchErrorPolicy,
+    registers: &[String],
+    outputs: &mut Vec<DataRepresentation>,
+) -> Result<Vec<Option<DataRepresentation>>, OperationError> {
+    outputs.push(branches_value(
+        branches.iter().cloned().map(DataRepresentation::String),
+    ));
+
+    let mut baked = Vec::with_capacity(branches.len());
+    let mut branch_steps = Vec::with_capacity(branches.len());
+
+    for branch in branches {
+        let input = DataRepresentation::String(branch.clone());
+        let state = RecipeState {
+            registers: registers.to_vec(),
+            ..RecipeState::new(input)
+        };
+        match bake_steps(body, offset, state) {
+            Ok(branch) => {
+                baked.push(Some(branch.output));
+                branch_steps.push(branch.steps);
+            }
+            Err(err) => match on_error {
+                BranchErrorPolicy::FailFast => return Err(err),
+                BranchErrorPolicy::Ignore => baked.push(None),
+                BranchErrorPolicy::KeepOriginal => {
+                    baked.push(Some(DataRepresentation::String(branch)))
+                }
+            },
+        }
+    }
+
+    let executed = branch_steps.iter().map(Vec::len).max().unwrap_or_default();
+    for step in 0..executed {
+        outputs.push(branches_value(
+            branch_steps
+                .iter()
+                .filter_map(|steps| steps.get(step).cloned()),
+        ));
+    }
+
+    Ok(baked)
+}
+
+/// Index of the Merge closing the Fork or Subsection at `start`, nested ones are skipped.
+fn matching_merge(steps: &[RecipeStep], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, step) in steps.iter().enumerate().skip(start + 1) {
+        match step.op {
+            Operations::Fork | Operations::Subsection => depth += 1,
+            Operations::Merge if depth == 0 => return Some(idx),
+            Operations::Merge => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn branches_value(branches: impl Iterator<Item = DataRepresentation>) -> DataRepresentation {
+    DataRepresentation::Json(Value::Array(
+        branches
+            .map(|branch| serde_json::to_value(branch).expect("output is serializable"))
+            .collect(),
+    ))
+}
+
+/// Index of the Label step named `name`.
+fn find_label(steps: &[RecipeStep], name: &str) -> Result<usize, OperationError> {
+    for (idx, step) in steps.iter().enumerate() {
+        if step.op == Operations::Label && Label.name(&params_object(&step.params))? == name {
+            return Ok(idx);
+        }
+    }
+
+    Err(OperationError::invalid_parameter(
+        "params.label",
+        format!("Label `{name}` not found."),
+        format!("Метка `{name}` не найдена."),
+    ))
+}
+
+/// Replaces `$R0`, `$R1`... in every string of `params`, unknown registers stay as is.
+fn substitute_registers(params: &Value, registers: &[String]) -> Value {
+    if registers.is_empty() {
+        return params.clone();
+    }
+
+    match params {
+        Value::String(string) => Value::String(
+            REGISTER
+                .replace_all(string, |captures: &regex::Captures| {
+                    captures[1]
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|idx| registers.get(idx))
+                        .cloned()
+                        .unwrap_or_else(|| captures[0].to_string())
+                })
+                .into_owned(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .iter()
+                .map(|value| substitute_registers(value, registers))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), substitute_registers(value, registers)))
+                .collect(),
+        ),
+        value => value.clone(),
+    }
+}
+
+static REGISTER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\$R(\d+)").unwrap());
+
+/// Data as text for regex steps, invalid UTF-8 is replaced.
+fn data_text(data: &DataRepresentation) -> String {
+    match data {
+        DataRepresentation::String(string) => string.clone(),
+        data => String::from_utf8_lossy(&data.clone().into_bytes()).into_owned(),
+    }
+}
+
+/// Steps may leave out `params`, flow control reads them as an empty object.
+fn params_object(params: &Value) -> Value {
+    match params {
+        Value::Null => Value::Object(Default::default()),
+        params => params.clone(),
+    }
+}
+
+fn step_error(idx: usize, step: &RecipeStep, err: OperationError) -> OperationError {
+    match err {
+        err @ OperationError::RecipeStep { .. } => err,
+        err => OperationError::RecipeStep {
+            step: idx + 1,
+            op: step.op.name(),
+            source: Box::new(err),
+        },
+    }
+}
+
+impl Operation<'_, DeserializeMeDaddy, RecipeOutput> for RunRecipe {
+    fn run(&self, request: DeserializeMeDaddy) -> Result<RecipeOutput, OperationError> {
+        request.recipe.bake(request.input)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct DeserializeMeDaddy {
+    input: DataRepresentation,
+    input_format: Option<SupportedFormats>,
+    recipe: Recipe,
+}
+
+impl_operation_request!();
+
+/// Bakes a recipe sent as json payload with this structure
+/// ``` json
+/// {
+///     "input": string,
+///     "input_format": Option<SupportedFormats>,
+///     "recipe": [
+///         { "op": Operations, "params": object }
+///     ]
+/// }
+/// ```
+/// Answer contains the final `output` and the output of every step in `steps`.
+pub struct RunRecipe;
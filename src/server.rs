@@ -0,0 +1,153 @@
+use axum::{
+    body::Bytes,
+    extract::{rejection::BytesRejection, DefaultBodyLimit, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+
+use crate::{
+    catalogue, run_operations, CancellationToken, DebugRecipe, ExecutionPolicy, OperationError,
+    Operations, RunRecipe,
+};
+
+/// Bodies bigger than this are answered with `resource_limit_exceeded`.
+pub const DEFAULT_MAX_REQUEST_SIZE: usize = 2 * 1024 * 1024;
+
+/// Routes of the api
+/// - `POST /api/{name}` runs an operation, the body is the payload described in its docs
+/// - `POST /api/recipe` bakes a recipe, see [`RunRecipe`]
+/// - `POST /api/recipe/debug` runs a recipe step by step, see [`DebugRecipe`]
+/// - `GET /api/info/{name}` returns the operation info with its schema
+/// - `GET /api/info` returns the whole catalogue
+///
+/// Every payload, recipes included, may add `input_format`, one of "utf8", "hex", "base64", "binary",
+/// "latin1" or "decimal", to send a text `input` that is decoded to bytes before the operation runs.
+/// Answers are `{ "Ok": ... }` or `{ "Err": ... }` as documented on every operation.
+/// Operations and recipes run under `policy`, a request is cancelled when its client disconnects.
+pub fn router(max_request_size: usize, policy: ExecutionPolicy) -> Router {
+    Router::new()
+        .route("/api/info", get(catalogue_handler))
+        .route("/api/info/{name}", get(info_handler))
+        .route("/api/recipe", post(recipe_handler))
+        .route("/api/recipe/debug", post(debug_handler))
+        .route("/api/{name}", post(operation_handler))
+        .layer(DefaultBodyLimit::max(max_request_size))
+        .with_state(policy)
+}
+
+async fn operation_handler(
+    State(policy): State<ExecutionPolicy>,
+    Path(name): Path<String>,
+    body: Result<Bytes, BytesRejection>,
+) -> Response {
+    let result = match name.parse::<Operations>() {
+        Ok(operation) => match read_body(body) {
+            Ok(request) => run_blocking(policy, move || operation.run_request(&request)).await,
+            Err(err) => Err(err),
+        },
+        Err(err) => Err(err),
+    };
+    answer(result)
+}
+
+async fn recipe_handler(
+    State(policy): State<ExecutionPolicy>,
+    body: Result<Bytes, BytesRejection>,
+) -> Response {
+    let result = match read_body(body) {
+        Ok(request) => run_blocking(policy, move || run_operations(RunRecipe, &request)).await,
+        Err(err) => Err(err),
+    };
+    answer(result)
+}
+
+async fn debug_handler(
+    State(policy): State<ExecutionPolicy>,
+    body: Result<Bytes, BytesRejection>,
+) -> Response {
+    let result = match read_body(body) {
+        Ok(request) => run_blocking(policy, move || run_operations(DebugRecipe, &request)).await,
+        Err(err) => Err(err),
+    };
+    answer(result)
+}
+
+async fn info_handler(Path(name): Path<String>) -> Response {
+    match name.parse::<Operations>() {
+        Ok(operation) => Json(operation.info_value()).into_response(),
+        Err(err) => answer::<()>(Err(err)),
+    }
+}
+
+async fn catalogue_handler() -> Response {
+    Json(catalogue()).into_response()
+}
+
+/// Operations are cpu bound, so they run outside of the async workers.
+/// Dropping the future, as axum does when the client goes away, cancels the work.
+async fn run_blocking<T, F>(policy: ExecutionPolicy, f: F) -> Result<T, OperationError>
+where
+    F: FnOnce() -> Result<T, OperationError> + Send + 'static,
+    T: Send + 'static,
+{
+    struct CancelOnDrop(CancellationToken);
+
+    impl Drop for CancelOnDrop {
+        fn drop(&mut self) {
+            self.0.cancel();
+        }
+    }
+
+    let token = CancellationToken::new();
+    let _cancel = CancelOnDrop(token.clone());
+
+    tokio::task::spawn_blocking(move || policy.enforce(&token, f))
+        .await
+        .unwrap_or_else(|err| {
+            Err(OperationError::Internal {
+                details: err.to_string(),
+            })
+        })
+}
+
+fn read_body(body: Result<Bytes, BytesRejection>) -> Result<String, OperationError> {
+    let body = body.map_err(|rejection| match rejection.status() {
+        StatusCode::PAYLOAD_TOO_LARGE => OperationError::resource_limit_exceeded(
+            "body",
+            "Request body is too large.",
+            "Тело запроса слишком большое.",
+        ),
+        _ => OperationError::InvalidJson {
+            details: rejection.body_text(),
+        },
+    })?;
+
+    String::from_utf8(body.into()).map_err(|err| OperationError::InvalidJson {
+        details: err.to_string(),
+    })
+}
+
+fn answer<T: Serialize>(result: Result<T, OperationError>) -> Response {
+    let status = match &result {
+        Ok(_) => StatusCode::OK,
+        Err(err) => status_code(err),
+    };
+    (status, Json(result)).into_response()
+}
+
+fn status_code(err: &OperationError) -> StatusCode {
+    match err {
+        OperationError::UnknownOperation { .. } => StatusCode::NOT_FOUND,
+        OperationError::ResourceLimitExceeded { param, .. } => match param.as_str() {
+            "body" | "input" => StatusCode::PAYLOAD_TOO_LARGE,
+            "deadline" | "cancellation" => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        },
+        OperationError::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        OperationError::RecipeStep { source, .. } => status_code(source),
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
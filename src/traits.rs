@@ -43,13 +43,13 @@ impl StringTrait for String {
 
     fn regex_replace_all(&self, regex_str: &str, replacement: &str) -> Result<String, String> {
         let re = regex::Regex::new(regex_str).map_err(|_| String::from("wrong regex"))?;
-        let output: String = re.replace_all(regex_str, replacement).to_string();
+        let output: String = re.replace_all(self, replacement).to_string();
         Ok(output)
     }
 
     fn regex_replace(&self, regex_str: &str, replacement: &str) -> Result<String, String> {
         let re = regex::Regex::new(regex_str).map_err(|_| String::from("wrong regex"))?;
-        let output: String = re.replace(regex_str, replacement).to_string();
+        let output: String = re.replace(self, replacement).to_string();
         Ok(output)
     }
     fn capitalize(&self) -> String {
@@ -1,19 +1,31 @@
-use crate::{libs::base64::from_base64, map, regex_check, traits::StringTrait};
-use num::{Integer, ToPrimitive};
-use serde::Deserialize;
+use crate::{
+    libs::base64::{from_base64, to_base64},
+    map,
+    traits::StringTrait,
+    OperationError, OutputFormat,
+};
+use num::{BigInt, FromPrimitive, Integer, Num, ToPrimitive};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
 use std::fmt::{Debug, LowerHex};
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum SupportedLanguages {
     EN,
     RU,
     #[allow(non_camel_case_types)]
     RU_WITH_YO,
+    DE,
+    UK,
+    EL,
+    #[allow(non_camel_case_types)]
+    LATIN_DIGITS,
 }
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, JsonSchema, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum SupportedFormats {
     BINARY,
@@ -21,21 +33,227 @@ pub enum SupportedFormats {
     HEX,
     BASE64,
     LATIN1,
+    DECIMAL,
 }
 
-#[derive(Eq, PartialEq, Debug)]
+impl SupportedFormats {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BINARY => "binary",
+            Self::UTF8 => "utf8",
+            Self::HEX => "hex",
+            Self::BASE64 => "base64",
+            Self::LATIN1 => "latin1",
+            Self::DECIMAL => "decimal",
+        }
+    }
+}
+
+/// Value passed between operations. Requests may send the `input` as a string,
+/// an array of bytes, a number, a list of files or any other json value.
+#[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug, Clone)]
+#[serde(untagged)]
 pub enum DataRepresentation {
     String(String),
     ByteArray(Vec<u8>),
+    Number(f64),
+    #[serde(serialize_with = "serialize_big_number", skip_deserializing)]
+    #[schemars(with = "String")]
+    BigNumber(BigInt),
+    ListFile(Vec<File>),
+    Json(Value),
 }
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, JsonSchema, Eq, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
 pub enum DataRepresentationInput {
     String,
-    ByteArray
+    ByteArray,
+    Number,
+    BigNumber,
+    ListFile,
+    Json,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Eq, PartialEq, Debug, Clone)]
+pub struct File {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+fn serialize_big_number<S: Serializer>(number: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&number.to_string())
+}
+
+impl DataRepresentation {
+    /// Keeps bytes as a string when they are valid UTF-8.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(string) => Self::String(string),
+            Err(err) => Self::ByteArray(err.into_bytes()),
+        }
+    }
+
+    pub fn data_type(&self) -> DataRepresentationInput {
+        match self {
+            Self::String(_) => DataRepresentationInput::String,
+            Self::ByteArray(_) => DataRepresentationInput::ByteArray,
+            Self::Number(_) => DataRepresentationInput::Number,
+            Self::BigNumber(_) => DataRepresentationInput::BigNumber,
+            Self::ListFile(_) => DataRepresentationInput::ListFile,
+            Self::Json(_) => DataRepresentationInput::Json,
+        }
+    }
+
+    pub fn convert(self, to: DataRepresentationInput) -> Result<Self, OperationError> {
+        if self.data_type() == to {
+            return Ok(self);
+        }
+
+        Ok(match to {
+            DataRepresentationInput::String => Self::String(self.into_string()?),
+            DataRepresentationInput::ByteArray => Self::ByteArray(self.into_bytes()),
+            DataRepresentationInput::Number => Self::Number(self.into_number()?),
+            DataRepresentationInput::BigNumber => Self::BigNumber(self.into_big_number()?),
+            DataRepresentationInput::ListFile => Self::ListFile(self.into_list_file()),
+            DataRepresentationInput::Json => Self::Json(self.into_json()?),
+        })
+    }
+
+    /// Number of bytes [`DataRepresentation::into_bytes`] returns, without converting.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::String(string) => string.len(),
+            Self::ByteArray(bytes) => bytes.len(),
+            Self::Number(number) => number.to_string().len(),
+            Self::BigNumber(number) => number.to_string().len(),
+            Self::ListFile(files) => files.iter().map(|file| file.data.len()).sum(),
+            Self::Json(value) => value.to_string().len(),
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::String(string) => string.into_bytes(),
+            Self::ByteArray(bytes) => bytes,
+            Self::Number(number) => number.to_string().into_bytes(),
+            Self::BigNumber(number) => number.to_string().into_bytes(),
+            Self::ListFile(files) => files.into_iter().flat_map(|file| file.data).collect(),
+            Self::Json(value) => value.to_string().into_bytes(),
+        }
+    }
+
+    pub fn into_string(self) -> Result<String, OperationError> {
+        match self {
+            Self::String(string) => Ok(string),
+            Self::Json(value) => Ok(value.to_string()),
+            data => byte_array_to_string(data.into_bytes()).map_err(|err| {
+                OperationError::invalid_input_encoding(
+                    err.clone(),
+                    format!("Входные данные не являются корректным UTF-8: {err}"),
+                )
+            }),
+        }
+    }
+
+    pub fn into_number(self) -> Result<f64, OperationError> {
+        match self {
+            Self::Number(number) => Ok(number),
+            Self::BigNumber(number) => number.to_f64().ok_or_else(|| {
+                OperationError::invalid_input_encoding(
+                    "Number is too big.",
+                    "Число слишком большое.",
+                )
+            }),
+            Self::Json(Value::Number(number)) => Ok(number.as_f64().unwrap()),
+            data => data.into_string()?.trim().parse::<f64>().map_err(|_| {
+                OperationError::invalid_input_encoding(
+                    "Input is not a number.",
+                    "Входные данные не являются числом.",
+                )
+            }),
+        }
+    }
+
+    pub fn into_big_number(self) -> Result<BigInt, OperationError> {
+        match self {
+            Self::BigNumber(number) => Ok(number),
+            Self::Number(number) if number.fract() == 0.0 => Ok(BigInt::from_f64(number).unwrap()),
+            Self::Number(_) => Err(OperationError::invalid_input_encoding(
+                "Input is not an integer.",
+                "Входные данные не являются целым числом.",
+            )),
+            data => BigInt::from_str_radix(data.into_string()?.trim(), 10).map_err(|_| {
+                OperationError::invalid_input_encoding(
+                    "Invalid symbols found in string.",
+                    "В строке найдены недопустимые символы.",
+                )
+            }),
+        }
+    }
+
+    pub fn into_json(self) -> Result<Value, OperationError> {
+        match self {
+            Self::Json(value) => Ok(value),
+            Self::Number(number) => Ok(Value::from(number)),
+            Self::BigNumber(number) => Ok(Value::String(number.to_string())),
+            Self::ListFile(files) => {
+                Ok(serde_json::to_value(files).expect("files are serializable"))
+            }
+            data => serde_json::from_slice(&data.into_bytes()).map_err(|err| {
+                OperationError::invalid_input_encoding(
+                    format!("Input is not a valid JSON: {err}."),
+                    format!("Входные данные не являются корректным JSON: {err}."),
+                )
+            }),
+        }
+    }
+
+    pub fn into_list_file(self) -> Vec<File> {
+        match self {
+            Self::ListFile(files) => files,
+            data => vec![File {
+                name: String::new(),
+                data: data.into_bytes(),
+            }],
+        }
+    }
+}
+
+/// Turns a serialized operation output back into a value for the next operation.
+/// Single key `hex`, `base64` and `uint8array` objects are unwrapped, arrays of bytes become `ByteArray`.
+impl From<Value> for DataRepresentation {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(string) => Self::String(string),
+            Value::Number(number) => Self::Number(number.as_f64().unwrap()),
+            Value::Array(ref array) => match value_to_bytes(array) {
+                Some(bytes) => Self::ByteArray(bytes),
+                None => Self::Json(value),
+            },
+            Value::Object(ref object) if object.len() == 1 => match object.iter().next().unwrap() {
+                (key, Value::String(string)) if key == "hex" || key == "base64" => {
+                    Self::String(string.to_owned())
+                }
+                (key, Value::Array(array)) if key == "uint8array" => match value_to_bytes(array) {
+                    Some(bytes) => Self::ByteArray(bytes),
+                    None => Self::Json(value),
+                },
+                _ => Self::Json(value),
+            },
+            value => Self::Json(value),
+        }
+    }
 }
 
-#[derive(Deserialize, Debug)]
+fn value_to_bytes(array: &[Value]) -> Option<Vec<u8>> {
+    array
+        .iter()
+        .map(|x| x.as_u64().and_then(|x| u8::try_from(x).ok()))
+        .collect()
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Debug)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum SupportedDelimiter {
     Space,
@@ -48,36 +266,169 @@ pub enum SupportedDelimiter {
     Colon,
 }
 
+impl SupportedDelimiter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::LineFeed => "\n",
+            Self::CRLF => "\r\n",
+            Self::Comma => ",",
+            Self::SemiColon => ";",
+            Self::Colon => ":",
+        }
+    }
+}
+
 impl std::fmt::Display for SupportedDelimiter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
+    }
+}
+
+/// Either one of the [`SupportedDelimiter`] names or any other string used as is.
+#[derive(Clone, Deserialize, JsonSchema, Debug)]
+#[serde(untagged)]
+pub enum Delimiter {
+    Supported(SupportedDelimiter),
+    Custom(String),
+}
+
+impl Delimiter {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Supported(delimiter) => delimiter.as_str(),
+            Self::Custom(delimiter) => delimiter,
+        }
+    }
+}
+
+/// Encoding of the bytes an operation produces, either a bare name or an object with options.
+/// ``` json
+/// "hex"
+/// { "format": "hex", "delimiter": "Colon", "uppercase": true }
+/// { "format": "base64", "alphabet": "A-Za-z0-9-_" }
+/// ```
+/// Options a format has no use for are ignored.
+/// The answer is the encoded value itself, `"ab01"`, except for HMAC, BLAKE2 and RSA which keep
+/// answering `{ "hex": "ab01" }` to the bare "hex", "base64" and "uint8array" names.
+#[derive(Clone, Deserialize, JsonSchema, Debug)]
+#[serde(untagged)]
+pub enum OutputEncoding {
+    Name(SupportedOutputFormat),
+    Options(OutputEncodingOptions),
+}
+
+/// - hex: lowercase pairs, no delimiter by default
+/// - base64: standard alphabet by default
+/// - raw: the bytes as they are, `uint8array` is the same
+/// - binary: octets delimited by a space by default
+/// - decimal: byte values delimited by a space by default
+/// - utf8: text, fails when the bytes are not valid UTF-8
+#[derive(Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SupportedOutputFormat {
+    #[default]
+    Hex,
+    Base64,
+    Raw,
+    Uint8Array,
+    Binary,
+    Decimal,
+    Utf8,
+}
+
+#[derive(Clone, Deserialize, JsonSchema, Debug)]
+pub struct OutputEncodingOptions {
+    format: SupportedOutputFormat,
+    delimiter: Option<Delimiter>,
+    uppercase: Option<bool>,
+    alphabet: Option<String>,
+}
+
+impl Default for OutputEncoding {
+    fn default() -> Self {
+        Self::Name(SupportedOutputFormat::default())
+    }
+}
+
+impl OutputEncoding {
+    /// Encodes `bytes`, `param` is the path of this format in the request and is named by errors.
+    pub fn encode(
+        &self,
+        bytes: Vec<u8>,
+        param: &str,
+    ) -> Result<DataRepresentation, OperationError> {
+        let (format, delimiter, uppercase, alphabet) = match self {
+            Self::Name(format) => (*format, None, false, None),
+            Self::Options(options) => (
+                options.format,
+                options.delimiter.as_ref().map(Delimiter::as_str),
+                options.uppercase.unwrap_or_default(),
+                options.alphabet.clone(),
+            ),
+        };
+
+        let join = |parts: Vec<String>, default: &str| parts.join(delimiter.unwrap_or(default));
+
+        Ok(DataRepresentation::String(match format {
+            SupportedOutputFormat::Hex => {
+                let hex = join(bytes.iter().map(|byte| format!("{byte:02x}")).collect(), "");
+                match uppercase {
+                    true => hex.to_uppercase(),
+                    false => hex,
+                }
+            }
+            SupportedOutputFormat::Base64 => {
+                to_base64(&bytes, alphabet).map_err(|err| match err {
+                    OperationError::InvalidParameter { message, .. } => {
+                        OperationError::InvalidParameter {
+                            param: format!("{param}.alphabet"),
+                            message,
+                        }
+                    }
+                    err => err,
+                })?
+            }
+            SupportedOutputFormat::Raw | SupportedOutputFormat::Uint8Array => {
+                return Ok(DataRepresentation::ByteArray(bytes))
+            }
+            SupportedOutputFormat::Binary => join(
+                bytes.iter().map(|byte| format!("{byte:08b}")).collect(),
+                " ",
+            ),
+            SupportedOutputFormat::Decimal => join(bytes.iter().map(u8::to_string).collect(), " "),
+            SupportedOutputFormat::Utf8 => String::from_utf8(bytes).map_err(|_| {
+                OperationError::invalid_parameter(
+                    param,
+                    "Output is not valid UTF-8, choose another format.",
+                    "Выходные данные не являются корректным UTF-8, выберите другой формат.",
+                )
+            })?,
+        }))
+    }
+
+    /// Same as [`OutputEncoding::encode`], but the bare "hex", "base64" and "uint8array" names
+    /// answer with the [`OutputFormat`] object, e.g. `{ "hex": "ab01" }`.
+    pub fn encode_tagged(
+        &self,
+        bytes: Vec<u8>,
+        param: &str,
+    ) -> Result<DataRepresentation, OperationError> {
+        let answer = match self {
+            Self::Name(SupportedOutputFormat::Hex) => OutputFormat::Hex(to_hex(&bytes)),
+            Self::Name(SupportedOutputFormat::Base64) => {
+                OutputFormat::Base64(to_base64(&bytes, None)?)
+            }
+            Self::Name(SupportedOutputFormat::Uint8Array) => OutputFormat::Uint8Array(bytes),
+            _ => return self.encode(bytes, param),
+        };
+
+        Ok(DataRepresentation::Json(
+            serde_json::to_value(answer).expect("output format is serializable"),
+        ))
     }
 }
 
-pub const EN_ALP: (&str, &str, &str, &str, u8, &str) = (
-    "abcdefghijklmnopqrstuvwxyz",
-    "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
-    "abcdefghiklmnopqrstuvwxyz",
-    "ABCDEFGHIKLMNOPQRSTUVWXYZ",
-    26,
-    r"^[a-zA-Z]+$",
-);
-pub const RU_ALP: (&str, &str, &str, &str, u8, &str) = (
-    "абвгдежзийклмнопрстуфхцчшщъыьэюя",
-    "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
-    "абвгдежзийклмнопрстуфхцчшщъыьэюя,.-=",
-    "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-=",
-    32,
-    "^[а-яА-Я]+$",
-);
-pub const RU_ALP_WITH_YO: (&str, &str, &str, &str, u8, &str) = (
-    "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
-    "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
-    "абвгдеёжзийклмнопрстуфхцчшщъыьэюя,.-",
-    "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-",
-    33,
-    r"^[а-яА-ЯёЁ]+$",
-);
 pub const NUM: (&str, &str) = ("0123456789", r"^\+?(0|[1-9]\d*)$");
 
 pub fn expand_alphabet_range(alphabet: &str) -> Vec<char> {
@@ -169,31 +520,32 @@ pub fn convert_to_byte_array(
             Err(e) => Err(e),
         },
         SupportedFormats::UTF8 => Ok(string.as_bytes().to_vec()),
-        SupportedFormats::LATIN1 => Ok(Vec::new()),
+        SupportedFormats::LATIN1 => string
+            .chars()
+            .map(|char| {
+                u8::try_from(char).map_err(|_| format!("Character `{char}` is not in Latin-1"))
+            })
+            .collect(),
+        SupportedFormats::DECIMAL => string
+            .split(|char: char| char.is_whitespace() || char == ',')
+            .filter(|number| !number.is_empty())
+            .map(|number| {
+                number
+                    .parse::<u8>()
+                    .map_err(|_| format!("`{number}` is not a byte"))
+            })
+            .collect(),
     }
 }
 
+/// Decodes binary octets. Tokens are split on `delim` or on any whitespace when it is `None`,
+/// every token is then read in chunks of `byte_len` digits (8 by default).
 pub fn from_binary(
     data: &str,
     delim: Option<&str>,
     byte_len: Option<usize>,
-) -> Result<Vec<u8>, String> {
-    if byte_len.unwrap_or(8) < 1 {
-        return Err("Byte length must be a positive integer".to_string());
-    };
-
-    let delim = char_repr(delim.unwrap_or("Space"));
-    let data = data.replace(delim, " ");
-
-    let mut output: Vec<u8> = Vec::new();
-    for i in data.split_whitespace() {
-        match u8::from_str_radix(i, 2) {
-            Ok(data) => output.push(data),
-            Err(e) => return Err(e.to_string()),
-        }
-    }
-
-    Ok(output)
+) -> Result<Vec<u8>, OperationError> {
+    from_radix(data, delim, byte_len.unwrap_or(8), 2)
 }
 
 pub fn to_hex(data: &[u8]) -> String {
@@ -201,29 +553,72 @@ pub fn to_hex(data: &[u8]) -> String {
         .fold(String::new(), |out, x| format!("{out}{x:02x}"))
 }
 
+/// Decodes hex pairs, `48656c6c6f`, `48 65 6c 6c 6f` and `48:65:6c:6c:6f` with `Some(":")` are the same.
 pub fn from_hex(
     data: &str,
     delim: Option<&str>,
     byte_len: Option<usize>,
-) -> Result<Vec<u8>, String> {
-    if byte_len.unwrap_or(8) < 1 {
-        return Err("Byte length must be a positive integer".to_string());
-    }
+) -> Result<Vec<u8>, OperationError> {
+    from_radix(data, delim, byte_len.unwrap_or(2), 16)
+}
 
-    let mut output: Vec<u8> = Vec::new();
+fn from_radix(
+    data: &str,
+    delim: Option<&str>,
+    byte_len: usize,
+    radix: u32,
+) -> Result<Vec<u8>, OperationError> {
+    if byte_len < 1 {
+        return Err(OperationError::invalid_parameter(
+            "params.byte_length",
+            "Byte length must be a positive integer.",
+            "Длина байта должна быть положительным целым числом.",
+        ));
+    }
 
-    let delim = char_repr(delim.unwrap_or("Space"));
+    let tokens: Vec<&str> = match delim {
+        Some(delim) if !delim.is_empty() => data.split(delim).map(str::trim).collect(),
+        _ => data.split_whitespace().collect(),
+    };
 
-    for i in data.split(&delim) {
-        match u8::from_str_radix(i, 16) {
-            Ok(data) => output.push(data),
-            Err(e) => return Err(e.to_string()),
+    let mut output = Vec::new();
+    for token in tokens.into_iter().filter(|token| !token.is_empty()) {
+        let chars: Vec<char> = token.chars().collect();
+        for chunk in chars.chunks(byte_len) {
+            let chunk: String = chunk.iter().collect();
+            let byte = u8::from_str_radix(&chunk, radix).map_err(|_| {
+                OperationError::invalid_input_encoding(
+                    format!("`{chunk}` is not a base {radix} byte."),
+                    format!("`{chunk}` не является байтом в системе счисления {radix}."),
+                )
+            })?;
+            output.push(byte);
         }
     }
 
     Ok(output)
 }
 
+/// Compiles a regex sent in `param`, flags are applied like `(?i)` and `(?m)` would be.
+pub fn build_regex(
+    pattern: &str,
+    case_insensitive: bool,
+    multiline: bool,
+    param: &str,
+) -> Result<regex::Regex, OperationError> {
+    regex::RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .multi_line(multiline)
+        .build()
+        .map_err(|err| {
+            OperationError::invalid_parameter(
+                param,
+                format!("Invalid regex: {err}"),
+                format!("Некорректное регулярное выражение: {err}"),
+            )
+        })
+}
+
 pub fn from_decimal(data: &str, delim: Option<&str>) -> Result<Vec<usize>, String> {
     let mut output = Vec::new();
     for i in data.split(char_repr(delim.unwrap_or("Space"))) {
@@ -235,32 +630,6 @@ pub fn from_decimal(data: &str, delim: Option<&str>) -> Result<Vec<usize>, Strin
     Ok(output)
 }
 
-pub fn validate_lang(text: &str, lang: &SupportedLanguages) -> bool {
-    let re = match lang {
-        SupportedLanguages::EN => r"^[a-zA-Z\p{P}\s\d]+$",
-        SupportedLanguages::RU => r"^[а-яА-Я\p{P}\s\d]+$",
-        SupportedLanguages::RU_WITH_YO => r"^[а-яА-ЯёЁ\p{P}\s\d]+$",
-    };
-    regex_check!(re => text)
-}
-
-pub fn get_alphabet(
-    lang: &SupportedLanguages,
-) -> (
-    &'static str,
-    &'static str,
-    &'static str,
-    &'static str,
-    u8,
-    &'static str,
-) {
-    match lang {
-        SupportedLanguages::EN => EN_ALP,
-        SupportedLanguages::RU => RU_ALP,
-        SupportedLanguages::RU_WITH_YO => RU_ALP_WITH_YO,
-    }
-}
-
 #[inline]
 pub fn get_char_by_index<T: Integer + ToPrimitive>(text: &str, index: T) -> char {
     text.chars().nth(index.to_usize().unwrap()).unwrap()
@@ -0,0 +1,88 @@
+use operations::{Batch, DataRepresentation, OperationError, Operations, SupportedFormats};
+use serde_json::{json, Value};
+
+fn inputs(count: usize) -> Vec<DataRepresentation> {
+    (0..count)
+        .map(|i| DataRepresentation::String(format!("line {i}")))
+        .collect()
+}
+
+#[test]
+fn results_match_single_runs_in_order() {
+    let params = json!({ "size": "sha256" });
+    let expected: Vec<_> = inputs(100)
+        .into_iter()
+        .map(|input| Operations::SHA2.run(input, &params).unwrap())
+        .map(|output| output.into_string().unwrap())
+        .collect();
+
+    for threads in [1, 3, 0] {
+        let batch = Batch::new(Operations::SHA2, &params)
+            .unwrap()
+            .threads(threads);
+        let outputs: Vec<_> = batch
+            .run(inputs(100))
+            .into_iter()
+            .map(|output| output.unwrap().into_string().unwrap())
+            .collect();
+
+        assert_eq!(outputs, expected, "{threads} threads");
+    }
+}
+
+#[test]
+fn inputs_are_decoded_with_input_format() {
+    let batch = Batch::new(Operations::ReverseString, &Value::Null)
+        .unwrap()
+        .input_format(SupportedFormats::HEX);
+    let outputs = batch.run(vec![
+        DataRepresentation::String("6162".to_string()),
+        DataRepresentation::String("zz".to_string()),
+    ]);
+
+    assert_eq!(outputs[0], Ok(DataRepresentation::String("ba".to_string())));
+    assert_eq!(
+        outputs[1].as_ref().unwrap_err().code(),
+        "invalid_input_encoding"
+    );
+}
+
+#[test]
+fn params_are_checked_once() {
+    let err = Batch::new(Operations::SHA2, &json!({ "size": "sha1024" }))
+        .err()
+        .unwrap();
+    assert_eq!(err.param(), Some("params.size"));
+
+    assert!(Batch::new(Operations::MD5, &Value::Null).is_ok());
+    assert!(matches!(
+        Batch::new(
+            Operations::Bcrypt,
+            &json!({ "rounds": 40, "version": "2b" })
+        ),
+        Err(OperationError::InvalidParameter { .. })
+    ));
+}
+
+#[test]
+fn every_input_gets_its_own_result() {
+    let batch = Batch::new(Operations::FromBase64, &json!({ "strict_mode": true }))
+        .unwrap()
+        .threads(2);
+    let results = batch.run(vec![
+        DataRepresentation::String("aGk=".to_string()),
+        DataRepresentation::String("!!!".to_string()),
+        DataRepresentation::String("bWFu".to_string()),
+    ]);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &DataRepresentation::String("hi".to_string())
+    );
+    assert!(results[1].is_err());
+    assert_eq!(
+        results[2].as_ref().unwrap(),
+        &DataRepresentation::String("man".to_string())
+    );
+}
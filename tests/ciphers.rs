@@ -0,0 +1,448 @@
+use operations::{OperationError, Operations};
+use serde_json::{json, Value};
+
+fn run(operation: Operations, input: &str, params: Value) -> Result<Value, OperationError> {
+    operation.run_request(&json!({ "input": input, "params": params }).to_string())
+}
+
+fn output(operation: Operations, input: &str, params: Value) -> String {
+    run(operation, input, params)
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn builtin_alphabets_round_trip() {
+    let cases = [
+        ("de", "Grüße aus Köln", "Schlüssel"),
+        ("uk", "Привіт, ґанок!", "ключ"),
+        ("el", "Καλημερα κοσμε", "κλειδι"),
+        ("latin_digits", "agent 007", "k3y"),
+        ("ru_with_yo", "Ёлки-палки", "ёж"),
+    ];
+
+    for (lang, text, key) in cases {
+        let affine = json!({ "lang": lang, "a": 7, "b": 8 });
+        let encoded = output(Operations::AffineCipherEncode, text, affine.clone());
+        assert_ne!(encoded, text);
+        assert_eq!(
+            output(Operations::AffineCipherDecode, &encoded, affine),
+            text
+        );
+
+        let atbash = json!({ "lang": lang });
+        let encoded = output(Operations::AtbashCipher, text, atbash.clone());
+        assert_eq!(output(Operations::AtbashCipher, &encoded, atbash), text);
+
+        let vigenere = json!({ "lang": lang, "key": key });
+        let encoded = output(Operations::VigenereCipherEncode, text, vigenere.clone());
+        assert_eq!(
+            output(Operations::VigenereCipherDecode, &encoded, vigenere),
+            text
+        );
+    }
+}
+
+#[test]
+fn custom_alphabet() {
+    let lang = json!({ "lower": "etaoinshrd" });
+
+    assert_eq!(
+        output(
+            Operations::AtbashCipher,
+            "Hear the rain",
+            json!({ "lang": lang })
+        ),
+        "Adht rad thni"
+    );
+    assert_eq!(
+        output(
+            Operations::A1Z26CipherEncode,
+            "sent",
+            json!({ "delimiter": "Comma", "lang": lang })
+        ),
+        "7,1,6,2"
+    );
+
+    let err = run(
+        Operations::AtbashCipher,
+        "abc",
+        json!({ "lang": { "lower": "abca" } }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.lang.lower"));
+
+    let err = run(
+        Operations::VigenereCipherEncode,
+        "abc",
+        json!({ "lang": { "lower": "abc", "upper": "AB" }, "key": "b" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.lang.upper"));
+}
+
+#[test]
+fn a1z26_uses_delimiter_and_alphabet() {
+    let params = json!({ "delimiter": "SemiColon" });
+    assert_eq!(
+        output(Operations::A1Z26CipherEncode, "Hi!", params.clone()),
+        "8;9"
+    );
+    assert_eq!(output(Operations::A1Z26CipherDecode, "8;9", params), "hi");
+
+    let params = json!({ "delimiter": "CRLF", "lang": "ru" });
+    let encoded = output(Operations::A1Z26CipherEncode, "мир", params.clone());
+    assert_eq!(encoded, "13\r\n9\r\n17");
+    assert_eq!(
+        output(Operations::A1Z26CipherDecode, &encoded, params),
+        "мир"
+    );
+}
+
+#[test]
+fn bacon_uses_alphabet() {
+    let params = json!({
+        "bacon_alphabet": "Complete",
+        "translation": "0/1",
+        "invert_translation": false,
+        "lang": "ru"
+    });
+    let mut encode = params.clone();
+    encode["keep_extra_character"] = json!(false);
+
+    let encoded = output(Operations::BaconCipherEncode, "мир", encode);
+    assert_eq!(encoded, "01100 01000 10000");
+    assert_eq!(
+        output(Operations::BaconCipherDecode, &encoded, params),
+        "мир"
+    );
+}
+
+#[test]
+fn bifid_round_trips() {
+    let cases = [
+        ("en", "key", "hello", "cmodh"),
+        ("en", "Schrodinger", "Hello, World!", "Cfosb, Lzwyv!"),
+        (
+            "ru",
+            "ключ",
+            "тестовое сообщение на русском",
+            "суййтишм дчурмндцъ жь нруцсвщ",
+        ),
+    ];
+
+    for (lang, keyword, plaintext, ciphertext) in cases {
+        let params = json!({ "lang": lang, "keyword": keyword });
+        assert_eq!(
+            output(Operations::BifidCipherEncode, plaintext, params.clone()),
+            ciphertext
+        );
+        assert_eq!(
+            output(Operations::BifidCipherDecode, ciphertext, params),
+            plaintext
+        );
+    }
+
+    let cases = [
+        ("ru_with_yo", "ёжик", "ёжик в тумане, 1975"),
+        ("de", "Schlüssel", "grüße aus köln"),
+        ("uk", "ключ", "привіт, світе!"),
+        ("el", "κλειδι", "Καλημερα κοσμε"),
+        ("latin_digits", "k3y", "agent 007"),
+    ];
+
+    for (lang, keyword, text) in cases {
+        let params = json!({ "lang": lang, "keyword": keyword });
+        let encoded = output(Operations::BifidCipherEncode, text, params.clone());
+        assert_ne!(encoded, text);
+        assert_eq!(
+            output(Operations::BifidCipherDecode, &encoded, params),
+            text
+        );
+    }
+
+    let err = run(
+        Operations::BifidCipherDecode,
+        "cmodh",
+        json!({ "lang": "en", "keyword": "ключ" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.keyword"));
+}
+
+#[test]
+fn caesar_respects_language() {
+    let cases = [
+        ("en", 3, "Hello, World!", "Khoor, Zruog!"),
+        ("ru", 3, "Привет, Мир!", "Тулеих, Плу!"),
+        ("ru_with_yo", -33, "Ёж", "Ёж"),
+        ("uk", 1, "Ґанок", "Дбопл"),
+        ("en", 29, "xyz 123", "abc 123"),
+    ];
+
+    for (lang, shift, plaintext, ciphertext) in cases {
+        let params = json!({ "lang": lang, "shift": shift });
+        assert_eq!(
+            output(Operations::CaesarCipherEncode, plaintext, params.clone()),
+            ciphertext
+        );
+        assert_eq!(
+            output(Operations::CaesarCipherDecode, ciphertext, params),
+            plaintext
+        );
+    }
+}
+
+#[test]
+fn rotations() {
+    assert_eq!(
+        output(Operations::ROT13, "Hello, World!", json!(null)),
+        "Uryyb, Jbeyq!"
+    );
+    assert_eq!(
+        output(
+            Operations::ROT13,
+            "Agent 007",
+            json!({ "amount": -21, "rotate_upper": false, "rotate_numbers": true })
+        ),
+        "Aljsy 996"
+    );
+    assert_eq!(
+        output(Operations::ROT47, "Hello, World!", json!({})),
+        "w6==@[ (@C=5P"
+    );
+    assert_eq!(
+        output(Operations::ROT47, "w6==@[ (@C=5P", json!({ "amount": -47 })),
+        "Hello, World!"
+    );
+
+    let text = "Hello, Мир! \u{1F600}";
+    let encoded = output(Operations::ROT8000, text, json!(null));
+    assert_eq!(&encoded[..3], "籑");
+    assert!(encoded.ends_with(" \u{1F600}"));
+    assert_eq!(output(Operations::ROT8000, &encoded, json!(null)), text);
+}
+
+#[test]
+fn brute_force_finds_the_key() {
+    let plaintext = "Встретимся завтра в парке у старого моста";
+
+    let encoded = output(
+        Operations::CaesarCipherEncode,
+        plaintext,
+        json!({ "lang": "ru", "shift": 5 }),
+    );
+    let candidates = run(
+        Operations::CaesarCipherBruteForce,
+        &encoded,
+        json!({ "lang": "ru", "limit": 2 }),
+    )
+    .unwrap();
+    assert_eq!(candidates.as_array().unwrap().len(), 2);
+    assert_eq!(candidates[0]["shift"], 5);
+    assert_eq!(candidates[0]["output"], plaintext);
+
+    let text = "Meet me near the old tower at seven";
+    let encoded = output(
+        Operations::AffineCipherEncode,
+        text,
+        json!({ "lang": "en", "a": 5, "b": 8 }),
+    );
+    let candidates = run(
+        Operations::AffineCipherBruteForce,
+        &encoded,
+        json!({ "lang": "en" }),
+    )
+    .unwrap();
+    assert_eq!(candidates.as_array().unwrap().len(), 5);
+    assert_eq!(candidates[0]["a"], 5);
+    assert_eq!(candidates[0]["b"], 8);
+    assert_eq!(candidates[0]["output"], text);
+
+    let err = run(
+        Operations::CaesarCipherBruteForce,
+        "Grüße",
+        json!({ "lang": "de" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.lang"));
+
+    let err = run(
+        Operations::AffineCipherBruteForce,
+        "2024",
+        json!({ "lang": "en" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("input"));
+}
+
+#[test]
+fn vigenere_key_recovery() {
+    let cases = [
+        (
+            "en",
+            "lemon",
+            "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, it was the season of Light, it was the season of Darkness, it was the spring of hope, it was the winter of despair.",
+        ),
+        (
+            "en",
+            "lemon",
+            "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, it was the season of Light.",
+        ),
+        (
+            "ru",
+            "ключ",
+            "Все счастливые семьи похожи друг на друга, каждая несчастливая семья несчастлива по-своему. Все смешалось в доме Облонских. Жена узнала, что муж был в связи с бывшею в их доме француженкою-гувернанткой, и объявила мужу, что не может жить с ним в одном доме.",
+        ),
+    ];
+
+    for (lang, key, plaintext) in cases {
+        let encoded = output(
+            Operations::VigenereCipherEncode,
+            plaintext,
+            json!({ "lang": lang, "key": key }),
+        );
+        let candidates = run(
+            Operations::VigenereCipherKeyRecovery,
+            &encoded,
+            json!({ "lang": lang }),
+        )
+        .unwrap();
+
+        assert_eq!(candidates[0]["key"], key);
+        assert_eq!(
+            candidates[0]["output"],
+            plaintext.chars().take(80).collect::<String>()
+        );
+        assert!(candidates.as_array().unwrap().len() <= 3);
+    }
+
+    let err = run(
+        Operations::VigenereCipherKeyRecovery,
+        "Я!",
+        json!({ "lang": "ru" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("input"));
+}
+
+#[test]
+fn vigenere_family_round_trips() {
+    let ciphers = [
+        (
+            Operations::BeaufortCipherEncode,
+            Operations::BeaufortCipherDecode,
+            json!({ "variant": false }),
+        ),
+        (
+            Operations::BeaufortCipherEncode,
+            Operations::BeaufortCipherDecode,
+            json!({ "variant": true }),
+        ),
+        (
+            Operations::AutokeyCipherEncode,
+            Operations::AutokeyCipherDecode,
+            json!({}),
+        ),
+        (
+            Operations::GronsfeldCipherEncode,
+            Operations::GronsfeldCipherDecode,
+            json!({ "key": "31415" }),
+        ),
+        (
+            Operations::PortaCipherEncode,
+            Operations::PortaCipherDecode,
+            json!({}),
+        ),
+    ];
+    let texts = [
+        ("en", "key", "Attack at Dawn!"),
+        ("ru", "ключ", "Атака на рассвете!"),
+        ("de", "schlüssel", "Grüße aus Köln"),
+        ("el", "κλειδι", "Καλημερα κοσμε"),
+    ];
+
+    for (encode, decode, cipher_params) in ciphers {
+        for (lang, key, text) in texts {
+            let mut params = json!({ "lang": lang, "key": key });
+            params
+                .as_object_mut()
+                .unwrap()
+                .extend(cipher_params.as_object().unwrap().clone());
+
+            let encoded = output(encode, text, params.clone());
+            assert_ne!(encoded, text);
+            assert_eq!(output(decode, &encoded, params), text);
+        }
+    }
+}
+
+#[test]
+fn vigenere_family_vectors() {
+    let cases = [
+        (
+            Operations::BeaufortCipherEncode,
+            "Hello, World!",
+            json!({ "lang": "en", "key": "key" }),
+            "Danzq, Cwnnh!",
+        ),
+        (
+            Operations::BeaufortCipherDecode,
+            "Danzq, Cwnnh!",
+            json!({ "lang": "en", "key": "key" }),
+            "Hello, World!",
+        ),
+        (
+            Operations::BeaufortCipherEncode,
+            "Hello, World!",
+            json!({ "lang": "en", "key": "key", "variant": true }),
+            "Xanbk, Yennt!",
+        ),
+        (
+            Operations::AutokeyCipherEncode,
+            "attackatdawn",
+            json!({ "lang": "en", "key": "queenly" }),
+            "qnxepvytwtwp",
+        ),
+        (
+            Operations::GronsfeldCipherEncode,
+            "Hello, World!",
+            json!({ "lang": "en", "key": "31415" }),
+            "Kfpmt, Zpvmi!",
+        ),
+        (
+            Operations::PortaCipherEncode,
+            "Hello, World!",
+            json!({ "lang": "en", "key": "key" }),
+            "Ztxqm, Kjcxv!",
+        ),
+        (
+            Operations::PortaCipherDecode,
+            "Ztxqm, Kjcxv!",
+            json!({ "lang": "en", "key": "key" }),
+            "Hello, World!",
+        ),
+    ];
+
+    for (operation, input, params, expected) in cases {
+        assert_eq!(output(operation, input, params), expected);
+    }
+
+    let err = run(
+        Operations::GronsfeldCipherEncode,
+        "Hello",
+        json!({ "lang": "en", "key": "key" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.key"));
+
+    let err = run(
+        Operations::PortaCipherEncode,
+        "Привіт",
+        json!({ "lang": "uk", "key": "ключ" }),
+    )
+    .unwrap_err();
+    assert_eq!(err.param(), Some("params.lang"));
+}
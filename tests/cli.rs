@@ -0,0 +1,148 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use serde_json::{json, Value};
+
+fn chef(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_chef"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn runs_an_operation_on_stdin() {
+    let output = chef(&["run", "SHA2", "--param", "size=sha256"], "hello");
+    assert_eq!(
+        stdout(&output),
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+
+    let output = chef(&["run", "ReverseString", "--output", "hex"], "ab");
+    assert_eq!(stdout(&output), "6261");
+
+    let output = chef(
+        &["run", "ReverseString", "--input-format", "base64"],
+        "YWI=\n",
+    );
+    assert_eq!(stdout(&output), "ba");
+}
+
+#[test]
+fn params_follow_the_schema() {
+    // `radix` is an integer, `key` a string even when it looks like a number.
+    let output = chef(&["run", "FromBase", "--param", "radix=16"], "ff");
+    assert_eq!(stdout(&output), "255");
+
+    let output = chef(
+        &[
+            "run",
+            "ADD",
+            "--param",
+            "key=10",
+            "--param",
+            "key_format=hex",
+        ],
+        "ab",
+    );
+    assert_eq!(stdout(&output), "qr");
+
+    let params = temp_file(
+        "chef_params.json",
+        &json!({ "key": "10", "key_format": "utf8" }).to_string(),
+    );
+    let output = chef(
+        &[
+            "run",
+            "ADD",
+            "--params-file",
+            params.to_str().unwrap(),
+            "--param",
+            "key_format=hex",
+        ],
+        "ab",
+    );
+    assert_eq!(stdout(&output), "qr");
+}
+
+#[test]
+fn bakes_a_recipe_file() {
+    let recipe = temp_file(
+        "chef_recipe.json",
+        &json!([
+            { "op": "ToBase64", "params": {} },
+            { "op": "ReverseString" }
+        ])
+        .to_string(),
+    );
+    let input = temp_file("chef_input.txt", "hello");
+
+    let output = chef(
+        &[
+            "recipe",
+            recipe.to_str().unwrap(),
+            "--input",
+            input.to_str().unwrap(),
+        ],
+        "",
+    );
+    assert_eq!(stdout(&output), "=8GbsVGa");
+}
+
+#[test]
+fn lists_and_describes_operations() {
+    let list = stdout(&chef(&["list"], ""));
+    assert!(list.lines().any(|line| line == "Hashing:"));
+    assert!(list.lines().any(|line| line == "    SHA2"));
+
+    let info: Value = serde_json::from_str(&stdout(&chef(&["info", "Bcrypt"], ""))).unwrap();
+    assert_eq!(info["name"], "Bcrypt");
+    assert!(info["schema"]["$defs"]["Params"]["properties"]["rounds"].is_object());
+}
+
+#[test]
+fn errors_exit_with_failure() {
+    let output = chef(&["run", "NoSuchOperation"], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error[unknown_operation]: Unknown operation `NoSuchOperation`.\n"
+    );
+
+    let output = chef(&["run", "FromBase", "--param", "radix=99"], "ff");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("error[invalid_parameter]"));
+}
@@ -0,0 +1,86 @@
+use operations::{DataRepresentation, OperationError, Recipe};
+use serde_json::json;
+
+fn output(recipe: &Recipe, input: &str) -> String {
+    recipe
+        .bake(DataRepresentation::String(input.to_string()))
+        .unwrap()
+        .output
+        .into_string()
+        .unwrap()
+}
+
+fn param(err: OperationError) -> String {
+    match err {
+        OperationError::InvalidParameter { param, .. } => param,
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[test]
+fn imports_cyberchef_json() {
+    let recipe = Recipe::from_cyberchef(&json!([
+        { "op": "From Base64", "args": ["A-Za-z0-9+/=", true, false] },
+        { "op": "Reverse", "args": ["Character"] },
+        { "op": "MD5", "args": [], "disabled": true },
+        { "op": "SHA2", "args": ["256", 64, 160] }
+    ]))
+    .unwrap();
+
+    assert_eq!(recipe.steps.len(), 3);
+    assert_eq!(
+        output(&recipe, "aGVsbG8="),
+        "0baf982fcab396fdb1c6d82f8f1eb0d2aea9cdd347fb244cf0b2c748df350069"
+    );
+}
+
+#[test]
+fn chef_format_round_trips() {
+    let url = "https://gchq.github.io/CyberChef/#recipe=Fork('%5C%5Cn','%20',false)From_Base64('A-Za-z0-9%2B/%3D',true,false)Merge(true)Reverse('Character')&input=YUdrPQpiV0Z1";
+    let recipe = Recipe::from_chef_format(url).unwrap();
+
+    assert_eq!(output(&recipe, "aGk=\nbWFu"), "nam ih");
+
+    let chef = recipe.to_chef_format().unwrap();
+    assert_eq!(
+        chef,
+        "Fork('\\\\n',' ',false)From_Base64('A-Za-z0-9+/=',true,false)Merge(true)Reverse('Character')"
+    );
+    let reimported = Recipe::from_chef_format(&chef).unwrap();
+    assert_eq!(
+        reimported.to_cyberchef().unwrap(),
+        recipe.to_cyberchef().unwrap()
+    );
+}
+
+#[test]
+fn reports_every_unsupported_operation() {
+    let err = Recipe::from_chef_format("MD5()Rail_Fence_Cipher_Encode(2,0)Magic(3,false,false,'')To_Morse_Code('-/.','Space','Line feed')").unwrap_err();
+
+    match err {
+        OperationError::InvalidParameter { param, message } => {
+            assert_eq!(param, "recipe[1].op");
+            assert!(message.en.contains("`Rail Fence Cipher Encode`"));
+            assert!(message.en.contains("`To Morse Code`"));
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+
+    let err = Recipe::from_chef_format("MD5()Magic(3,true,true,'flag')").unwrap_err();
+    assert_eq!(param(err), "recipe[1].args[1]");
+    assert!(Recipe::from_chef_format("Magic(5,false,false,'')").is_ok());
+}
+
+#[test]
+fn export_rejects_inexpressible_params() {
+    let recipe: Recipe = serde_json::from_value(json!([
+        { "op": "SHA2", "params": { "size": "sha256" } },
+        { "op": "Fork", "params": { "split_delimiter": ",", "on_error": "keep_original" } }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        param(recipe.to_cyberchef().unwrap_err()),
+        "recipe[1].params.on_error"
+    );
+}
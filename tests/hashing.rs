@@ -0,0 +1,286 @@
+use std::io::Cursor;
+
+use operations::{hash_reader, ErrorMessage, HashAlgorithm, Hasher, Operations};
+use serde_json::{json, Value};
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn run(operation: Operations, request: Value) -> Value {
+    operation.run_request(&request.to_string()).unwrap()
+}
+
+/// Published answers: RFC 1319, 1320 and 1321 for MD2, MD4 and MD5, FIPS 180 for SHA-1 and SHA-2,
+/// FIPS 202 for SHA-3.
+fn known_answers() -> Vec<(HashAlgorithm, Operations, Value, &'static str, &'static str)> {
+    vec![
+        (
+            HashAlgorithm::MD2,
+            Operations::MD2,
+            Value::Null,
+            "abc",
+            "da853b0d3f88d99b30283a69e6ded6bb",
+        ),
+        (
+            HashAlgorithm::MD4,
+            Operations::MD4,
+            Value::Null,
+            "abc",
+            "a448017aaf21d8525fc10ae87aa6729d",
+        ),
+        (
+            HashAlgorithm::MD5,
+            Operations::MD5,
+            Value::Null,
+            "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            "57edf4a22be3c955ac49da2e2107b67a",
+        ),
+        (
+            HashAlgorithm::SHA1,
+            Operations::SHA1,
+            Value::Null,
+            "abc",
+            "a9993e364706816aba3e25717850c26c9cd0d89d",
+        ),
+        (
+            HashAlgorithm::SHA256,
+            Operations::SHA2,
+            json!({ "size": "sha256" }),
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+        (
+            HashAlgorithm::SHA512_256,
+            Operations::SHA2,
+            json!({ "size": "sha512_256" }),
+            "abc",
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+        ),
+        (
+            HashAlgorithm::SHA3_384,
+            Operations::SHA3,
+            json!({ "size": "sha384" }),
+            "abc",
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25",
+        ),
+    ]
+}
+
+#[test]
+fn hashes_give_known_answers() {
+    for (algorithm, operation, params, input, digest) in known_answers() {
+        let mut hasher = Hasher::new(algorithm).unwrap();
+        for chunk in input.as_bytes().chunks(7) {
+            hasher.update(chunk);
+        }
+
+        assert_eq!(to_hex(&hasher.finalize()), digest, "{algorithm:?}");
+        assert_eq!(
+            run(operation, json!({ "input": input, "params": params })),
+            json!(digest),
+            "{algorithm:?}"
+        );
+    }
+}
+
+#[test]
+fn reader_gives_known_answer() {
+    let input = vec![b'a'; 1_000_000];
+    let digest = hash_reader(HashAlgorithm::SHA256, Cursor::new(&input)).unwrap();
+
+    assert_eq!(
+        to_hex(&digest),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+}
+
+/// RFC 2104 for HMAC-MD5 and RFC 4231 test case 2 for HMAC-SHA-256.
+#[test]
+fn hmac_gives_known_answers() {
+    let cases = [
+        (
+            HashAlgorithm::MD5,
+            "md5",
+            "750c783e6ab0b503eaa86e310a5db738",
+        ),
+        (
+            HashAlgorithm::SHA256,
+            "sha256",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+    ];
+
+    for (algorithm, hash_function, digest) in cases {
+        let mut hasher = Hasher::hmac(algorithm, b"Jefe").unwrap();
+        hasher.update(b"what do ya want ");
+        hasher.update(b"for nothing?");
+        assert_eq!(to_hex(&hasher.finalize()), digest);
+
+        let answer = run(
+            Operations::HMAC,
+            json!({
+                "input": "what do ya want for nothing?",
+                "params": {
+                    "key": "Jefe",
+                    "key_format": "utf8",
+                    "hash_function": hash_function,
+                    "output_format": "hex"
+                }
+            }),
+        );
+        assert_eq!(answer, json!({ "hex": digest }));
+    }
+}
+
+/// First keyed answer of the BLAKE2 reference test vectors: empty input, key 00 01 .. 3f.
+#[test]
+fn keyed_blake2_gives_known_answer() {
+    let key = (0..64).collect::<Vec<u8>>();
+    let digest = "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568";
+
+    let hasher = Hasher::keyed(HashAlgorithm::Blake2b(64), &key).unwrap();
+    assert_eq!(to_hex(&hasher.finalize()), digest);
+
+    let answer = run(
+        Operations::Blake2b,
+        json!({
+            "input": "",
+            "params": {
+                "key": to_hex(&key),
+                "key_format": "hex",
+                "size": "512",
+                "output_format": "hex"
+            }
+        }),
+    );
+    assert_eq!(answer, json!({ "hex": digest }));
+    assert!(Hasher::new(HashAlgorithm::Blake2s(48)).is_err());
+}
+
+#[test]
+fn keyed_hashes_keep_their_answer_shape() {
+    let blake2s = |output_format: Value| {
+        run(
+            Operations::Blake2s,
+            json!({ "input": "hi", "params": { "size": "128", "output_format": output_format } }),
+        )
+    };
+    let hex = blake2s(json!("hex"))["hex"].as_str().unwrap().to_owned();
+
+    assert_eq!(hex.len(), 32);
+    assert_eq!(
+        blake2s(json!({ "format": "hex", "uppercase": true })),
+        json!(hex.to_uppercase())
+    );
+    assert!(blake2s(json!("base64"))["base64"].is_string());
+    assert_eq!(
+        blake2s(json!("uint8array"))["uint8array"]
+            .as_array()
+            .unwrap()
+            .len(),
+        16
+    );
+    assert!(blake2s(json!("raw")).is_array());
+}
+
+#[test]
+fn output_formats_share_one_encoder() {
+    let md5 = |output_format: Value| {
+        run(
+            Operations::MD5,
+            json!({ "input": "hi", "params": { "output_format": output_format } }),
+        )
+    };
+    let digest = [
+        0x49, 0xf6, 0x8a, 0x5c, 0x84, 0x93, 0xec, 0x2c, 0x0b, 0xf4, 0x89, 0x82, 0x1c, 0x21, 0xfc,
+        0x3b,
+    ];
+
+    assert_eq!(md5(Value::Null), json!(to_hex(&digest)));
+    assert_eq!(
+        md5(json!({ "format": "hex", "delimiter": "Colon", "uppercase": true })),
+        json!("49:F6:8A:5C:84:93:EC:2C:0B:F4:89:82:1C:21:FC:3B")
+    );
+    assert_eq!(md5(json!("base64")), json!("SfaKXIST7CwL9ImCHCH8Ow=="));
+    assert_eq!(md5(json!("raw")), json!(digest));
+    assert_eq!(md5(json!("uint8array")), json!(digest));
+
+    let adler32 = |output_format: Value| {
+        run(
+            Operations::Adler32CheckSum,
+            json!({ "input": "hi", "params": { "output_format": output_format } }),
+        )
+    };
+    assert_eq!(adler32(json!("hex")), json!("013b00d2"));
+    assert_eq!(adler32(json!("decimal")), json!("1 59 0 210"));
+    assert_eq!(
+        adler32(json!({ "format": "decimal", "delimiter": "Comma" })),
+        json!("1,59,0,210")
+    );
+    assert_eq!(
+        adler32(json!({ "format": "binary", "delimiter": "," })),
+        json!("00000001,00111011,00000000,11010010")
+    );
+
+    let err = Operations::SHA1
+        .run_request(&json!({ "input": "hi", "params": { "output_format": "utf8" } }).to_string())
+        .unwrap_err();
+    assert_eq!(
+        serde_json::to_value(err).unwrap()["param"],
+        "params.output_format"
+    );
+}
+
+#[test]
+fn input_format_decodes_input() {
+    let sha256 = |input: &str, input_format: &str| {
+        run(
+            Operations::SHA2,
+            json!({ "input": input, "input_format": input_format, "params": { "size": "sha256" } }),
+        )
+    };
+    let expected = sha256("\u{0}\u{ff}hi", "utf8");
+
+    assert_ne!(expected, sha256("\u{0}\u{ff}hi", "latin1"));
+    assert_eq!(sha256("00ff6869", "hex"), sha256("\u{0}\u{ff}hi", "latin1"));
+    assert_eq!(
+        sha256("AP9oaQ==", "base64"),
+        sha256("0 255 104 105", "decimal")
+    );
+    assert_eq!(
+        sha256("00000000 11111111 01101000 01101001", "binary"),
+        sha256("00ff6869", "hex")
+    );
+
+    let reversed = Operations::ReverseString
+        .run_request(&json!({ "input": "6f6c6c6568", "input_format": "hex" }).to_string())
+        .unwrap();
+    assert_eq!(reversed, "hello");
+
+    let err = Operations::SHA1
+        .run_request(&json!({ "input": "Ā", "input_format": "latin1" }).to_string())
+        .unwrap_err();
+    assert_eq!(err.code(), "invalid_input_encoding");
+    assert_eq!(
+        err.message().en,
+        "Input is not valid latin1: Character `Ā` is not in Latin-1."
+    );
+
+    let err = Operations::SHA1
+        .run_request(&json!({ "input": "zz", "input_format": "hex" }).to_string())
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        ErrorMessage::new(
+            "Input is not valid hex: `zz` is not a base 16 byte.",
+            "Входные данные не соответствуют формату hex: `zz` is not a base 16 byte."
+        )
+    );
+
+    let schema = &Operations::SHA1.info_value()["schema"];
+    assert_eq!(
+        schema["properties"]["input_format"]["anyOf"][0]["$ref"],
+        "#/$defs/SupportedFormats"
+    );
+}
@@ -0,0 +1,94 @@
+use operations::{OperationError, Operations};
+use serde_json::{json, Value};
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+fn magic(input: &str, params: Value) -> Result<Vec<Value>, OperationError> {
+    Operations::Magic
+        .run_request(&json!({ "input": input, "params": params }).to_string())
+        .map(|results| results.as_array().unwrap().clone())
+}
+
+fn ops(result: &Value) -> Vec<&str> {
+    result["recipe"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|step| step["op"].as_str().unwrap())
+        .collect()
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn binary(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("{byte:08b}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn base64(data: &str) -> String {
+    Operations::ToBase64
+        .run_request(&json!({ "input": data, "params": {} }).to_string())
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn detects_and_ranks_encodings() {
+    let cases = [
+        (base64(TEXT), "FromBase64"),
+        (hex(TEXT.as_bytes()), "FromHex"),
+        (binary(TEXT.as_bytes()), "FromBinary"),
+    ];
+
+    for (input, op) in cases {
+        let results = magic(&input, json!({ "depth": 1 })).unwrap();
+        let best = &results[0];
+        assert_eq!(ops(best), [op], "{input}");
+        assert_eq!(best["output"], TEXT);
+        assert_eq!(best["language"], "en");
+
+        let scores: Vec<f64> = results
+            .iter()
+            .map(|r| r["score"].as_f64().unwrap())
+            .collect();
+        assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+}
+
+#[test]
+fn decodes_nested_encodings_up_to_depth() {
+    let input = base64(&hex(TEXT.as_bytes()));
+
+    let results = magic(&input, json!({ "depth": 2 })).unwrap();
+    assert_eq!(ops(&results[0]), ["FromBase64", "FromHex"]);
+    assert_eq!(results[0]["output"], TEXT);
+
+    let results = magic(&input, json!({ "depth": 1 })).unwrap();
+    assert!(results.iter().all(|result| ops(result).len() <= 1));
+    assert!(results.iter().all(|result| result["output"] != TEXT));
+}
+
+#[test]
+fn depth_is_limited() {
+    for depth in [0, 6] {
+        let err = magic(TEXT, json!({ "depth": depth })).unwrap_err();
+        assert_eq!(err.code(), "invalid_parameter");
+        assert_eq!(err.param(), Some("params.depth"));
+    }
+}
+
+#[test]
+fn file_signatures_are_ranked_first() {
+    let results = magic("iVBORw0KGgoAAAANSUhEUg==", json!({ "limit": 1 })).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(ops(&results[0]), ["FromBase64"]);
+    assert_eq!(results[0]["file_type"], "PNG image");
+    assert_eq!(results[0]["score"], 1.0);
+}
@@ -0,0 +1,128 @@
+use operations::{ErrorMessage, OperationError, Operations};
+use serde_json::{json, Value};
+
+fn run(operation: Operations, input: &str, params: Value) -> Result<Value, OperationError> {
+    operation.run_request(&json!({ "input": input, "params": params }).to_string())
+}
+
+#[test]
+fn filter_keeps_the_delimiter() {
+    let filter = |input: &str, delimiter: &str, invert_condition: bool| {
+        run(
+            Operations::Filter,
+            input,
+            json!({ "delimiter": delimiter, "regex": "\\d", "invert_condition": invert_condition }),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(filter("a1 b c2", "Space", false), json!("a1 c2"));
+    assert_eq!(filter("a1 b c2", "Space", true), json!("b"));
+    assert_eq!(filter("a1\nb\nc2", "Line feed", false), json!("a1\nc2"));
+    assert_eq!(filter("a1;b;c2", "Semi-colon", false), json!("a1;c2"));
+}
+
+#[test]
+fn line_numbers_are_padded_to_the_line_count() {
+    let output = |input: &str| {
+        Operations::AddLineNumbers
+            .run_request(&json!({ "input": input }).to_string())
+            .unwrap()
+    };
+
+    assert_eq!(output(""), "1 ");
+    assert_eq!(output("a\nb"), "1 a\n2 b");
+    assert_eq!(
+        output(&["x"; 10].join("\n")),
+        format!(
+            "{}10 x",
+            (1..10).map(|i| format!(" {i} x\n")).collect::<String>()
+        )
+    );
+}
+
+#[test]
+fn empty_key_is_rejected() {
+    for operation in [Operations::ADD, Operations::AND] {
+        let err = run(
+            operation,
+            "hello",
+            json!({ "key": "", "key_format": "utf8" }),
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), "invalid_parameter");
+        assert_eq!(err.param(), Some("params.key"));
+    }
+}
+
+#[test]
+fn bitwise_output_format() {
+    let params = |output_format: Value| json!({ "key": "01", "key_format": "hex", "output_format": output_format });
+
+    assert_eq!(
+        run(Operations::ADD, "ab", params(Value::Null)).unwrap(),
+        json!("bc")
+    );
+    assert_eq!(
+        run(Operations::ADD, "ab", params(json!("hex"))).unwrap(),
+        json!("6263")
+    );
+    assert_eq!(
+        run(Operations::AND, "ab", params(json!("decimal"))).unwrap(),
+        json!("1 0")
+    );
+}
+
+#[test]
+fn decoders_output_format() {
+    assert_eq!(
+        run(Operations::FromHex, "00ff", json!({})).unwrap(),
+        json!([0, 255])
+    );
+    assert_eq!(
+        run(
+            Operations::FromHex,
+            "00ff",
+            json!({ "output_format": "decimal" })
+        )
+        .unwrap(),
+        json!("0 255")
+    );
+    assert_eq!(
+        run(
+            Operations::FromBase64,
+            "AP8=",
+            json!({ "output_format": "hex" })
+        )
+        .unwrap(),
+        json!("00ff")
+    );
+    assert_eq!(
+        run(
+            Operations::FromBinary,
+            "00000000 11111111",
+            json!({ "output_format": { "format": "hex", "uppercase": true } })
+        )
+        .unwrap(),
+        json!("00FF")
+    );
+}
+
+#[test]
+fn validation_errors_name_the_parameter_in_every_language() {
+    let err = run(
+        Operations::Bcrypt,
+        "hello",
+        json!({ "rounds": 40, "version": "2b" }),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.param(), Some("params.rounds"));
+    assert_eq!(
+        err.message(),
+        ErrorMessage::new(
+            "Invalid value of `params.rounds`: The number must be `<= 31`.",
+            "Недопустимое значение параметра `params.rounds`: The number must be `<= 31`."
+        )
+    );
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use operations::{
+    run_operations, Batch, CaesarCipherBruteForce, CancellationToken, DataRepresentation,
+    ExecutionPolicy, OperationError, Operations, Recipe,
+};
+use serde_json::{json, Value};
+
+fn limit_param(err: &OperationError) -> Option<&str> {
+    match err {
+        OperationError::ResourceLimitExceeded { .. } => err.param(),
+        OperationError::RecipeStep { source, .. } => limit_param(source),
+        _ => None,
+    }
+}
+
+fn argon2(memory: u32, iterations: u32, parallelism: u32) -> String {
+    json!({
+        "input": "hello",
+        "params": {
+            "salt": "somesalt",
+            "iterations": iterations,
+            "memory": memory,
+            "parallelism": parallelism,
+            "hash_length": 16,
+            "argon2_type": "Argon2id",
+            "output_format": "encoded"
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn costs_are_checked_before_running() {
+    let policy = ExecutionPolicy {
+        max_memory_cost: Some(1024),
+        max_time_cost: Some(2),
+        max_parallelism: Some(2),
+        max_bcrypt_cost: Some(6),
+        ..Default::default()
+    };
+    let run = |operation: Operations, request: &str| {
+        policy.enforce(&CancellationToken::new(), || operation.run_request(request))
+    };
+
+    let err = run(Operations::Argon2, &argon2(1 << 20, 1, 1)).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.memory"));
+    let err = run(Operations::Argon2, &argon2(64, 3, 1)).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.iterations"));
+    let err = run(Operations::Argon2, &argon2(64, 1, 3)).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.parallelism"));
+    let encoded = run(Operations::Argon2, &argon2(64, 2, 2)).unwrap();
+
+    let mut request: Value = serde_json::from_str(&argon2(64, 1, 1)).unwrap();
+    request["params"]["hash_length"] = json!(u32::MAX);
+    let err = run(Operations::Argon2, &request.to_string()).unwrap_err();
+    assert_eq!(err.code(), "invalid_parameter");
+    assert_eq!(err.param(), Some("params.hash_length"));
+
+    let compare = json!({ "input": "hello", "params": { "encoded_hash": encoded } });
+    assert!(run(Operations::Argon2Compare, &compare.to_string()).is_ok());
+
+    let compare = json!({
+        "input": "hello",
+        "params": { "encoded_hash": "$argon2id$v=19$m=64,t=1,p=4$c29tZXNhbHQ$TCWBEqCFxPMkRbKkqBVyEQ" }
+    });
+    let err = run(Operations::Argon2Compare, &compare.to_string()).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.encoded_hash"));
+
+    let bcrypt = json!({ "input": "hello", "params": { "rounds": 7, "version": "2b" } });
+    let err = run(Operations::Bcrypt, &bcrypt.to_string()).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.rounds"));
+
+    let compare = json!({
+        "input": "hello",
+        "params": { "encoded_hash": "$2b$12$MVLmUxmyCWgCIsUwmGEPC.p3ZcnCXf5Qd5TKB8PE8aVGBGIXHXIqW" }
+    });
+    let err = run(Operations::BcryptCompare, &compare.to_string()).unwrap_err();
+    assert_eq!(limit_param(&err), Some("params.encoded_hash"));
+}
+
+#[test]
+fn jumps_stop_at_the_deadline() {
+    let recipe: Recipe = serde_json::from_value(json!([
+        { "op": "Label", "params": { "name": "again" } },
+        { "op": "ReverseString" },
+        { "op": "Jump", "params": { "label": "again", "max_jumps": 1000 } }
+    ]))
+    .unwrap();
+    let policy = ExecutionPolicy {
+        timeout: Some(Duration::ZERO),
+        ..Default::default()
+    };
+
+    let err = policy
+        .enforce(&CancellationToken::new(), || {
+            recipe.bake(DataRepresentation::String("abc".to_string()))
+        })
+        .unwrap_err();
+    assert_eq!(limit_param(&err), Some("deadline"));
+}
+
+#[test]
+fn cancellation_stops_long_loops() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let request = json!({ "input": "Uryyb jbeyq", "params": { "lang": "en" } }).to_string();
+
+    // `run_operations` skips the check every operation gets before it starts.
+    let err = ExecutionPolicy::default()
+        .enforce(&token, || run_operations(CaesarCipherBruteForce, &request))
+        .map(drop)
+        .unwrap_err();
+    assert_eq!(limit_param(&err), Some("cancellation"));
+    assert!(run_operations(CaesarCipherBruteForce, &request).is_ok());
+}
+
+#[test]
+fn cancelled_batch_fails_every_item() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let batch = Batch::new(Operations::MD5, &serde_json::Value::Null)
+        .unwrap()
+        .threads(2);
+
+    let results = ExecutionPolicy::default().enforce(&token, || {
+        batch.run(vec![DataRepresentation::String("a".to_string()); 4])
+    });
+    assert!(results
+        .iter()
+        .all(|result| limit_param(result.as_ref().unwrap_err()) == Some("cancellation")));
+
+    assert!(batch.run(vec![DataRepresentation::String("a".to_string())])[0].is_ok());
+}
+
+#[test]
+fn input_size_is_checked_for_every_step() {
+    let recipe: Recipe = serde_json::from_value(json!([
+        { "op": "ToBase64", "params": {} },
+        { "op": "ToBase64", "params": {} }
+    ]))
+    .unwrap();
+    let policy = ExecutionPolicy {
+        max_input_size: Some(6),
+        ..Default::default()
+    };
+
+    let err = policy
+        .enforce(&CancellationToken::new(), || {
+            recipe.bake(DataRepresentation::String("abcdef".to_string()))
+        })
+        .unwrap_err();
+    assert!(matches!(err, OperationError::RecipeStep { step: 2, .. }));
+    assert_eq!(limit_param(&err), Some("input"));
+}
@@ -0,0 +1,223 @@
+use operations::{
+    run_operations, DataRepresentation, DebugRecipe, DebugStatus, OperationError, Recipe,
+    RecipeOutput, RecipeState, RunRecipe,
+};
+use serde_json::{json, Value};
+
+fn bake(input: &str, recipe: Value) -> Result<RecipeOutput, OperationError> {
+    let recipe: Recipe = serde_json::from_value(recipe).unwrap();
+    recipe.bake(DataRepresentation::String(input.to_string()))
+}
+
+fn output(input: &str, recipe: Value) -> String {
+    bake(input, recipe).unwrap().output.into_string().unwrap()
+}
+
+#[test]
+fn fork_runs_steps_per_branch() {
+    let recipe = json!([
+        { "op": "Fork", "params": { "split_delimiter": "Line feed" } },
+        { "op": "FromBase64", "params": {} },
+        { "op": "Merge", "params": { "delimiter": " | " } },
+        { "op": "ReverseString" }
+    ]);
+
+    assert_eq!(output("aGk=\nbWFu", recipe), "nam | ih");
+}
+
+#[test]
+fn nested_forks_merge_in_order() {
+    let recipe = json!([
+        { "op": "Fork", "params": { "split_delimiter": "," } },
+        { "op": "Fork", "params": { "split_delimiter": "Space", "merge_delimiter": "+" } },
+        { "op": "ReverseString" },
+        { "op": "Merge" },
+        { "op": "ReverseString" },
+        { "op": "Merge", "params": { "delimiter": "Semi-colon" } }
+    ]);
+
+    assert_eq!(output("ab cd,ef", recipe), "cd+ab;ef");
+}
+
+#[test]
+fn fork_rejects_empty_split_delimiter() {
+    let err = bake(
+        "abc",
+        json!([{ "op": "Fork", "params": { "split_delimiter": "" } }]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, OperationError::RecipeStep { step: 1, .. }));
+    assert_eq!(err.param(), Some("params.split_delimiter"));
+}
+
+#[test]
+fn branch_errors_follow_policy() {
+    let recipe = |on_error: &str| {
+        json!([
+            { "op": "Fork", "params": { "split_delimiter": "Space", "on_error": on_error } },
+            { "op": "FromBase64", "params": { "strict_mode": true } }
+        ])
+    };
+
+    assert_eq!(output("aGk= !!! bWFu", recipe("ignore")), "hi man");
+    assert_eq!(
+        output("aGk= !!! bWFu", recipe("keep_original")),
+        "hi !!! man"
+    );
+
+    let err = bake("aGk= !!! bWFu", recipe("fail_fast")).unwrap_err();
+    assert!(matches!(err, OperationError::RecipeStep { step: 2, .. }));
+}
+
+#[test]
+fn registers_are_substituted_into_params() {
+    let recipe = json!([
+        { "op": "Register", "params": { "extractor": "key=(\\w+)\\n" } },
+        { "op": "Filter", "params": { "delimiter": "Line feed", "regex": "=", "invert_condition": true } },
+        { "op": "VigenereCipherDecode", "params": { "key": "$R0", "lang": "en" } }
+    ]);
+
+    assert_eq!(output("key=lemon\nlxfopvefrnhr", recipe), "attackatdawn");
+}
+
+#[test]
+fn jumps_stop_after_max_jumps() {
+    let recipe = json!([
+        { "op": "Label", "params": { "name": "again" } },
+        { "op": "ToBase64", "params": {} },
+        { "op": "Jump", "params": { "label": "again", "max_jumps": 2 } }
+    ]);
+
+    let result = bake("abc", recipe).unwrap();
+    assert_eq!(result.output.into_string().unwrap(), "V1ZkS2FnPT0=");
+    assert_eq!(result.steps.len(), 9);
+}
+
+#[test]
+fn conditional_jump_loops_while_matching() {
+    let recipe = json!([
+        { "op": "Label", "params": { "name": "decode" } },
+        { "op": "FromBase64", "params": {} },
+        {
+            "op": "ConditionalJump",
+            "params": { "regex": "^(?:[A-Za-z0-9+/]{4})+$|=$", "label": "decode" }
+        }
+    ]);
+
+    assert_eq!(output("WVVkV2MySkhPRDA9", recipe), "hello");
+}
+
+#[test]
+fn subsection_restores_surrounding_text() {
+    let recipe = |global: bool| {
+        json!([
+            {
+                "op": "Subsection",
+                "params": { "regex": "\"([a-z0-9+/]{4,}=+)\"", "capture_group": 1, "case_insensitive": true, "global": global }
+            },
+            { "op": "FromBase64", "params": {} },
+            { "op": "Merge" },
+            { "op": "ReverseString" }
+        ])
+    };
+    let input = r#"{"user": "YWRtaW4=", "pass": "aHVudGVyMg=="}"#;
+
+    assert_eq!(
+        output(input, recipe(true)),
+        r#"}"2retnuh" :"ssap" ,"nimda" :"resu"{"#
+    );
+    assert_eq!(
+        output(input, recipe(false)),
+        r#"}"==gMyVGduVHa" :"ssap" ,"nimda" :"resu"{"#
+    );
+}
+
+#[test]
+fn debug_pauses_at_breakpoints_and_resumes() {
+    let recipe: Recipe = serde_json::from_value(json!([
+        { "op": "FromBase64", "params": {} },
+        { "op": "ReverseString" },
+        { "op": "FromBase64", "params": { "strict_mode": true } }
+    ]))
+    .unwrap();
+    let input = DataRepresentation::String("aGVsbG8=".to_string());
+
+    let paused = recipe.debug(RecipeState::new(input), &[0]);
+    assert_eq!(paused.status, DebugStatus::Paused);
+    assert_eq!(paused.trace.len(), 1);
+    assert_eq!(
+        paused.trace[0].output,
+        Some(DataRepresentation::String("hello".to_string()))
+    );
+
+    let saved = serde_json::to_string(&paused.state).unwrap();
+    let state: RecipeState = serde_json::from_str(&saved).unwrap();
+    let failed = recipe.debug(state, &[]);
+
+    assert_eq!(failed.status, DebugStatus::Failed);
+    assert_eq!(
+        failed
+            .trace
+            .iter()
+            .map(|trace| trace.step)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(
+        failed.trace[1].input,
+        DataRepresentation::String("olleh".to_string())
+    );
+    assert!(failed.trace[1].error.is_some());
+    assert_eq!(failed.state.next_step, 2);
+    assert_eq!(failed.state.data, failed.trace[1].input);
+}
+
+#[test]
+fn debug_steps_over_fork_blocks() {
+    let recipe: Recipe = serde_json::from_value(json!([
+        { "op": "Fork", "params": { "split_delimiter": "Line feed" } },
+        { "op": "FromBase64", "params": {} },
+        { "op": "Merge", "params": { "delimiter": " " } },
+        { "op": "ReverseString" }
+    ]))
+    .unwrap();
+    let input = DataRepresentation::String("aGk=\nbWFu".to_string());
+
+    let paused = recipe.debug(RecipeState::new(input), &[1]);
+    assert_eq!(paused.status, DebugStatus::Paused);
+    assert_eq!(paused.state.next_step, 3);
+    assert_eq!(
+        paused.state.data,
+        DataRepresentation::String("hi man".to_string())
+    );
+
+    let finished = recipe.debug(paused.state, &[]);
+    assert_eq!(finished.status, DebugStatus::Finished);
+    assert_eq!(
+        finished.state.data,
+        DataRepresentation::String("nam ih".to_string())
+    );
+}
+
+#[test]
+fn recipe_requests_decode_input_format() {
+    let request = json!({
+        "input": "6f6c6c6568",
+        "input_format": "hex",
+        "recipe": [{ "op": "ReverseString" }]
+    })
+    .to_string();
+
+    let baked = run_operations(RunRecipe, &request).unwrap();
+    assert_eq!(
+        baked.output,
+        DataRepresentation::String("hello".to_string())
+    );
+
+    let debugged = run_operations(DebugRecipe, &request).unwrap();
+    assert_eq!(debugged.status, DebugStatus::Finished);
+    assert_eq!(
+        debugged.state.data,
+        DataRepresentation::String("hello".to_string())
+    );
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "server")]
+
+use axum::Router;
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use operations::{router, ExecutionPolicy, DEFAULT_MAX_REQUEST_SIZE};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+async fn send(
+    method: Method,
+    uri: &str,
+    body: impl Into<Body>,
+    app: Router,
+) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(body.into())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+fn app(max_request_size: usize) -> Router {
+    router(max_request_size, ExecutionPolicy::default())
+}
+
+async fn post(uri: &str, body: Value) -> (StatusCode, Value) {
+    send(
+        Method::POST,
+        uri,
+        body.to_string(),
+        app(DEFAULT_MAX_REQUEST_SIZE),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn runs_operation_by_name() {
+    let (status, body) = post(
+        "/api/SHA2",
+        json!({ "input": "hello", "params": { "size": "sha256" } }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        json!({ "Ok": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824" })
+    );
+}
+
+#[tokio::test]
+async fn reports_bad_parameter() {
+    let (status, body) = post(
+        "/api/Bcrypt",
+        json!({ "input": "hello", "params": { "rounds": 40, "version": "2b" } }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["Err"]["code"], "invalid_parameter");
+    assert_eq!(body["Err"]["param"], "params.rounds");
+}
+
+#[tokio::test]
+async fn reports_invalid_json() {
+    let (status, body) = send(
+        Method::POST,
+        "/api/MD5",
+        "{ \"input\": ",
+        app(DEFAULT_MAX_REQUEST_SIZE),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["Err"]["code"], "invalid_json");
+}
+
+#[tokio::test]
+async fn unknown_operation_is_not_found() {
+    let (status, body) = post("/api/Nope", json!({ "input": "hello" })).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["Err"]["code"], "unknown_operation");
+}
+
+#[tokio::test]
+async fn limits_request_size() {
+    let request = json!({ "input": "a".repeat(64), "params": { "size": "sha256" } });
+    let (status, body) = send(Method::POST, "/api/SHA2", request.to_string(), app(32)).await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["Err"]["code"], "resource_limit_exceeded");
+}
+
+#[tokio::test]
+async fn enforces_execution_policy() {
+    let policy = ExecutionPolicy {
+        max_bcrypt_cost: Some(10),
+        max_input_size: Some(16),
+        ..Default::default()
+    };
+    let app = || router(DEFAULT_MAX_REQUEST_SIZE, policy.clone());
+
+    let request = json!({ "input": "hello", "params": { "rounds": 12, "version": "2b" } });
+    let (status, body) = send(Method::POST, "/api/Bcrypt", request.to_string(), app()).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["Err"]["code"], "resource_limit_exceeded");
+    assert_eq!(body["Err"]["param"], "params.rounds");
+
+    let request = json!({ "input": "a".repeat(17), "params": { "size": "sha256" } });
+    let (status, body) = send(Method::POST, "/api/SHA2", request.to_string(), app()).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["Err"]["param"], "input");
+}
+
+#[tokio::test]
+async fn bakes_recipe() {
+    let (status, body) = post(
+        "/api/recipe",
+        json!({
+            "input": "aGVsbG8=",
+            "recipe": [
+                { "op": "FromBase64", "params": {} },
+                { "op": "ReverseString" }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["Ok"]["output"], "olleh");
+    assert_eq!(body["Ok"]["steps"], json!(["hello", "olleh"]));
+}
+
+#[tokio::test]
+async fn debugs_recipe_step_by_step() {
+    let recipe = json!([
+        { "op": "FromBase64", "params": {} },
+        { "op": "ReverseString" }
+    ]);
+
+    let (status, body) = post(
+        "/api/recipe/debug",
+        json!({ "input": "aGVsbG8=", "recipe": recipe, "breakpoints": [0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["Ok"]["status"], "paused");
+    assert_eq!(body["Ok"]["trace"][0]["input"], "aGVsbG8=");
+    assert_eq!(body["Ok"]["trace"][0]["output"], "hello");
+    assert!(body["Ok"]["trace"][0]["elapsed_ms"].is_number());
+
+    let (status, body) = post(
+        "/api/recipe/debug",
+        json!({ "state": body["Ok"]["state"], "recipe": recipe }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["Ok"]["status"], "finished");
+    assert_eq!(body["Ok"]["state"]["data"], "olleh");
+}
+
+#[tokio::test]
+async fn serves_info_and_catalogue() {
+    let (status, body) = send(Method::GET, "/api/info/MD5", Body::empty(), app(0)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "MD5");
+    assert_eq!(body["schema"]["title"], "MD5");
+
+    let (status, body) = send(Method::GET, "/api/info", Body::empty(), app(0)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["operations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|operation| operation["name"] == "SHA2" && operation["category"] == "Hashing"));
+}